@@ -0,0 +1,112 @@
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day01>();
+
+const DIGIT_WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The first digit of `line`, either as a character or as one of `words`.
+fn first_digit_or_word(line: &str, words: &[(String, u32)]) -> Option<u32> {
+    for (i, c) in line.char_indices() {
+        if let Some(digit) = c.to_digit(10) {
+            return Some(digit);
+        }
+
+        for (word, digit) in words {
+            if line[i..].starts_with(word.as_str()) {
+                return Some(*digit);
+            }
+        }
+    }
+
+    None
+}
+
+fn reversed(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+/// Lines without any digit do not count.
+fn calibrate(lines: &[String], words: &[(String, u32)]) -> u32 {
+    let words_reversed: Vec<(String, u32)> = words
+        .iter()
+        .map(|(word, digit)| (reversed(word), *digit))
+        .collect();
+
+    lines
+        .iter()
+        .filter_map(|line| {
+            let first = first_digit_or_word(line, words)?;
+            let last = first_digit_or_word(&reversed(line), &words_reversed)?;
+            Some(first * 10 + last)
+        })
+        .sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        Ok(parser.parse_by_lines(|line| line))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(calibrate(input, &[]))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        let words: Vec<(String, u32)> = DIGIT_WORDS
+            .iter()
+            .map(|&(word, digit)| (String::from(word), digit))
+            .collect();
+        Some(calibrate(input, &words))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn examples() {
+        aoc_example!(Day01, EXAMPLE_1, part1 = 142, part2 = 142);
+        aoc_example!(Day01, EXAMPLE_2, part2 = 281);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc01::DAY.main();
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day02>();
+
+/// Colours missing here have no limit.
+const MAX_CUBES: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// A game with all of its draws; as only the largest amount of each colour
+/// matters, the draws are not kept apart.
+pub struct Game {
+    id: u32,
+    cubes: Vec<(u32, String)>,
+}
+
+fn parse_game(line: String) -> Result<Game, String> {
+    let (game, rest) = line.split_once(':').ok_or("missing ':'")?;
+    let id = game
+        .split_whitespace()
+        .next_back()
+        .and_then(|id| id.parse().ok())
+        .ok_or("missing game id")?;
+
+    let mut cubes = Vec::new();
+    for draw in rest.split([',', ';']) {
+        let (amount, color) = draw
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| format!("invalid draw {:?}", draw))?;
+        let amount = amount.parse().map_err(|e| format!("{}: {:?}", e, amount))?;
+        cubes.push((amount, String::from(color.trim())));
+    }
+
+    Ok(Game { id, cubes })
+}
+
+fn min_amounts(game: &Game) -> HashMap<&str, u32> {
+    let mut amounts = HashMap::new();
+    for (amount, color) in &game.cubes {
+        let max = amounts.entry(color.as_str()).or_insert(0);
+        *max = (*max).max(*amount);
+    }
+    amounts
+}
+
+fn part1(games: &[Game]) -> u32 {
+    let limits = HashMap::from(MAX_CUBES);
+
+    games
+        .iter()
+        .filter(|game| {
+            game.cubes.iter().all(|(amount, color)| {
+                limits
+                    .get(color.as_str())
+                    .is_none_or(|limit| amount <= limit)
+            })
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+fn part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| {
+            let amounts = min_amounts(game);
+            ["red", "green", "blue"]
+                .iter()
+                .map(|color| amounts.get(color).copied().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_by_lines(parse_game)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day02, EXAMPLE, part1 = 8, part2 = 2286);
+    }
+}
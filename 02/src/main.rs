@@ -0,0 +1,3 @@
+fn main() {
+    aoc02::DAY.main();
+}
@@ -0,0 +1,135 @@
+use std::collections::{HashMap, HashSet};
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    geometry::Point,
+    grid::Grid,
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day03>();
+
+type Coord = Point<isize>;
+
+fn is_symbol(ch: char) -> bool {
+    ch != '.' && !ch.is_ascii_digit()
+}
+
+/// A number of the schematic with the coordinates of its digits.
+struct Number {
+    value: u32,
+    digits: Vec<Coord>,
+}
+
+fn find_numbers(grid: &Grid<char>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+
+    for (row, line) in grid.rows().enumerate() {
+        let mut current: Option<Number> = None;
+        for (col, ch) in line.iter().enumerate() {
+            match (ch.to_digit(10), current.as_mut()) {
+                (Some(digit), Some(number)) => {
+                    number.value = number.value * 10 + digit;
+                    number.digits.push(Point::new(row as isize, col as isize));
+                }
+                (Some(digit), None) => {
+                    current = Some(Number {
+                        value: digit,
+                        digits: vec![Point::new(row as isize, col as isize)],
+                    })
+                }
+                (None, _) => numbers.extend(current.take()),
+            }
+        }
+        numbers.extend(current);
+    }
+
+    numbers
+}
+
+fn part1(grid: &Grid<char>) -> u32 {
+    find_numbers(grid)
+        .iter()
+        .filter(|number| {
+            number.digits.iter().any(|digit| {
+                digit
+                    .neighbours8()
+                    .any(|n| grid.get(n.row, n.col).is_some_and(|&ch| is_symbol(ch)))
+            })
+        })
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> u32 {
+    let numbers = find_numbers(grid);
+    let references: HashMap<Coord, usize> = numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(i, number)| number.digits.iter().map(move |&digit| (digit, i)))
+        .collect();
+
+    grid.iter()
+        .filter(|(_, &ch)| ch == '*')
+        .map(|((row, col), _)| {
+            let gear = Point::new(row as isize, col as isize);
+            let adjacent: HashSet<usize> = gear
+                .neighbours8()
+                .filter_map(|n| references.get(&n).copied())
+                .collect();
+
+            if adjacent.len() == 2 {
+                adjacent.iter().map(|&i| numbers[i].value).product()
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Grid<char>;
+    type Answer = u32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_grid()
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day03, EXAMPLE, part1 = 4361, part2 = 467835);
+    }
+}
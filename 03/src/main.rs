@@ -0,0 +1,3 @@
+fn main() {
+    aoc03::DAY.main();
+}
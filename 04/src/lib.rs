@@ -0,0 +1,111 @@
+use std::collections::{HashMap, HashSet};
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day04>();
+
+type Card = (i64, HashSet<i32>, HashSet<i32>);
+
+fn parse_cards(line: &str) -> Result<HashSet<i32>, String> {
+    line.split_whitespace()
+        .map(|v| v.parse::<i32>().map_err(|e| format!("{}: {:?}", e, v)))
+        .collect()
+}
+
+fn parse_line(line: String) -> Result<Card, String> {
+    let (card, cards) = line.split_once(':').ok_or("missing ':'")?;
+    let card_id: i64 = card
+        .split(' ')
+        .next_back()
+        .and_then(|id| id.parse().ok())
+        .ok_or("missing card id")?;
+    let (my_cards, winning_cards) = cards.split_once('|').ok_or("missing '|'")?;
+    let my_cards = parse_cards(my_cards)?;
+    let winning_cards = parse_cards(winning_cards)?;
+
+    Ok((card_id, my_cards, winning_cards))
+}
+
+fn score(from_line: &Card) -> i64 {
+    let (_, my_cards, winning_cards) = from_line;
+    let same: u32 = my_cards.intersection(winning_cards).count() as u32;
+    if same == 0 {
+        return 0;
+    };
+    let res: i64 = 2i64.pow(same - 1);
+    res
+}
+
+fn part1(cards: &[Card]) -> i64 {
+    cards.iter().map(score).sum()
+}
+
+fn part2(cards: &[Card]) -> i64 {
+    let mut costs: HashMap<i64, i64> = HashMap::new();
+
+    cards
+        .iter()
+        .rev()
+        .map(|(id, my_cards, winning_cards)| {
+            let id = *id;
+            let winning_numbers: i64 = my_cards.intersection(winning_cards).count() as i64;
+            costs.insert(id, 1);
+            for i in 1..=winning_numbers {
+                let key = id + i;
+                if costs.contains_key(&key) {
+                    let cost = costs[&key];
+                    if let Some(value) = costs.get_mut(&id) {
+                        *value += cost;
+                    }
+                }
+            }
+            costs[&id]
+        })
+        .sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer = i64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_by_lines(parse_line)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day04, EXAMPLE, part1 = 13, part2 = 30);
+    }
+}
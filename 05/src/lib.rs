@@ -0,0 +1,331 @@
+use std::fmt::{self, Display};
+
+use rust_base::{
+    aoc::{ParseError, Parser, Section},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day05>();
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash)]
+struct Boundary {
+    src: i64,
+    dst: i64,
+    range: i64,
+}
+
+impl TryFrom<Vec<i64>> for Boundary {
+    type Error = String;
+
+    fn try_from(value: Vec<i64>) -> Result<Self, Self::Error> {
+        match value[..] {
+            [dst, src, range] => Ok(Boundary { src, dst, range }),
+            _ => Err(format!("expected 3 numbers, found {}", value.len())),
+        }
+    }
+}
+
+/// One map of the almanac: ids covered by a boundary are shifted by it, all
+/// other ids map to themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap {
+    // sorted by `src`, not overlapping
+    boundaries: Vec<Boundary>,
+}
+
+impl RangeMap {
+    /// Sorts the boundaries and merges neighbouring ones with the same
+    /// shift.
+    fn new(mut boundaries: Vec<Boundary>) -> Self {
+        boundaries.sort_by_key(|b| b.src);
+
+        let mut merged: Vec<Boundary> = Vec::with_capacity(boundaries.len());
+        for boundary in boundaries {
+            match merged.last_mut() {
+                Some(last)
+                    if last.src + last.range == boundary.src
+                        && last.dst + last.range == boundary.dst =>
+                {
+                    last.range += boundary.range
+                }
+                _ => merged.push(boundary),
+            }
+        }
+
+        RangeMap { boundaries: merged }
+    }
+
+    /// Splits `[start, end)` into the parts covered by a single boundary or by
+    /// none, in order, with the offset each of them is shifted by.
+    fn pieces(&self, start: i64, end: i64) -> Vec<(i64, i64, i64)> {
+        let mut res = Vec::new();
+        let mut at = start;
+
+        let first = self
+            .boundaries
+            .partition_point(|b| b.src + b.range <= start);
+        for boundary in &self.boundaries[first..] {
+            if boundary.src >= end {
+                break;
+            }
+            if at < boundary.src {
+                res.push((at, boundary.src, 0));
+                at = boundary.src;
+            }
+
+            let until = end.min(boundary.src + boundary.range);
+            res.push((at, until, boundary.dst - boundary.src));
+            at = until;
+        }
+
+        if at < end {
+            res.push((at, end, 0));
+        }
+        res
+    }
+
+    pub fn get(&self, id: i64) -> i64 {
+        let (_, _, offset) = self.pieces(id, id + 1)[0];
+        id + offset
+    }
+
+    /// The images of the ids in `[start, end)`, as disjoint intervals.
+    pub fn map_range(&self, start: i64, end: i64) -> Vec<(i64, i64)> {
+        self.pieces(start, end)
+            .into_iter()
+            .map(|(from, to, offset)| (from + offset, to + offset))
+            .collect()
+    }
+
+    /// The inverse mapping: the ids mapped into `[start, end)`, as disjoint
+    /// intervals in increasing order. The maps need not be one-to-one, so
+    /// there may be several of them.
+    pub fn preimage(&self, start: i64, end: i64) -> Vec<(i64, i64)> {
+        self.pieces(i64::MIN, i64::MAX)
+            .into_iter()
+            .filter_map(|(from, to, offset)| {
+                let low = start.max(from + offset);
+                let high = end.min(to + offset);
+                (low < high).then_some((low - offset, high - offset))
+            })
+            .collect()
+    }
+
+    /// The map that applies `self` and then `next`.
+    pub fn compose(&self, next: &RangeMap) -> RangeMap {
+        let mut boundaries = Vec::new();
+        // the pieces at the ends of the id space are never shifted
+        for (start, end, offset) in self.pieces(i64::MIN, i64::MAX) {
+            for (from, to, next_offset) in next.pieces(start + offset, end + offset) {
+                if offset + next_offset != 0 {
+                    boundaries.push(Boundary {
+                        src: from - offset,
+                        dst: from + next_offset,
+                        range: to - from,
+                    });
+                }
+            }
+        }
+
+        RangeMap::new(boundaries)
+    }
+}
+
+/// One line per boundary: `<ids> -> <images> (<shift>)`.
+impl Display for RangeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in &self.boundaries {
+            writeln!(
+                f,
+                "{}..{} -> {}..{} ({:+})",
+                b.src,
+                b.src + b.range,
+                b.dst,
+                b.dst + b.range,
+                b.dst - b.src
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Data {
+    seeds: Vec<i64>,
+    maps: Vec<RangeMap>,
+}
+
+impl Data {
+    /// The map from seeds to locations, as a single piecewise-linear
+    /// function.
+    pub fn seed_to_location(&self) -> RangeMap {
+        self.maps
+            .iter()
+            .fold(RangeMap::new(Vec::new()), |map, next| map.compose(next))
+    }
+}
+
+fn parse_numbers(line: &str) -> Result<Vec<i64>, String> {
+    line.split_whitespace()
+        .map(|v| v.parse::<i64>().map_err(|e| format!("{}: {:?}", e, v)))
+        .collect()
+}
+
+fn parse_boundary(line: &str) -> Result<Boundary, String> {
+    parse_numbers(line)?.try_into()
+}
+
+fn parse_seeds(line: &str) -> Result<Vec<i64>, String> {
+    let seeds = line.strip_prefix("seeds:").ok_or("expected seeds:")?;
+    let seeds = parse_numbers(seeds)?;
+    if seeds.is_empty() {
+        return Err(String::from("no seeds"));
+    }
+    // part 2 reads the seeds as pairs of a start and a length
+    if seeds.len() % 2 != 0 {
+        return Err(format!("expected pairs of numbers, found {}", seeds.len()));
+    }
+    if let Some(length) = seeds.iter().skip(1).step_by(2).find(|&&length| length <= 0) {
+        return Err(format!(
+            "seed ranges must not be empty, found length {}",
+            length
+        ));
+    }
+    Ok(seeds)
+}
+
+fn parse_sections(sections: Vec<Section>) -> Result<Data, ParseError> {
+    let mut sections = sections.into_iter();
+    let first = sections
+        .next()
+        .ok_or_else(|| ParseError::malformed(1, "", "missing seeds"))?;
+    let Some(line) = first.lines.first() else {
+        // a lone `seeds:` is taken for the header of an empty section
+        let header = format!("{}:", first.header.unwrap_or_default());
+        return Err(ParseError::malformed(
+            first.line - 1,
+            &header,
+            "missing seeds",
+        ));
+    };
+    let seeds = parse_seeds(line).map_err(|e| ParseError::malformed(first.line, line, e))?;
+    if let Some(extra) = first.lines.get(1) {
+        let reason = "expected a blank line after the seeds";
+        return Err(ParseError::malformed(first.line + 1, extra, reason));
+    }
+
+    let mut maps = Vec::new();
+    for section in sections {
+        let mut boundaries = Vec::new();
+        for (i, line) in section.lines.iter().enumerate() {
+            let boundary = parse_boundary(line)
+                .map_err(|e| ParseError::malformed(section.line + i, line, e))?;
+            boundaries.push(boundary);
+        }
+        maps.push(RangeMap::new(boundaries));
+    }
+
+    Ok(Data { seeds, maps })
+}
+
+fn part1(data: &Data) -> i64 {
+    let map = data.seed_to_location();
+    data.seeds.iter().map(|&seed| map.get(seed)).min().unwrap()
+}
+
+fn part2(data: &Data) -> i64 {
+    let map = data.seed_to_location();
+    data.seeds
+        .chunks_exact(2)
+        .flat_map(|chunk| map.map_range(chunk[0], chunk[0] + chunk[1]))
+        .map(|(start, _)| start)
+        .min()
+        .unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Data;
+    type Answer = i64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parse_sections(parser.parse_sections(|section| section))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day05, EXAMPLE, part1 = 35, part2 = 46);
+    }
+
+    #[test]
+    fn preimage() {
+        let data = Day05::parse(Parser::from_str(EXAMPLE)).unwrap();
+        let map = data.seed_to_location();
+        let seeds = map.preimage(40, 50);
+
+        for seed in 0..100 {
+            assert_eq!(
+                (40..50).contains(&map.get(seed)),
+                seeds
+                    .iter()
+                    .any(|&(start, end)| start <= seed && seed < end),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
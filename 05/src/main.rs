@@ -1,153 +1,69 @@
-use std::{
-    collections::HashSet,
-    env::args,
-    fs::File,
-    io::{self, BufRead, BufReader, Error},
-};
+use std::{env::args, process::exit};
 
-#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy, Hash)]
-struct Boundary {
-    src: i64,
-    dst: i64,
-    range: i64,
-}
+use aoc05::{Data, Day05};
+use rust_base::{
+    aoc::{ParseError, Parser},
+    solution::Solution,
+};
 
-impl From<Vec<i64>> for Boundary {
-    fn from(value: Vec<i64>) -> Self {
-        Boundary { src: value[1], dst: value[0], range: value[2] }
-    }
-}
+const USAGE: &str = "usage: aoc05 [input] [--part <1|2>] ...
+       aoc05 --table [input]
+       aoc05 --seeds-for <start> <end> [input]";
 
-struct Data {
-    seeds: Vec<i64>,
-    maps: Vec<HashSet<Boundary>>
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(2)
 }
 
-fn parse_boundary(line: String) -> Boundary {
-    let nums: Vec<i64> = line.trim().split(' ').map(|v|v.parse::<i64>().unwrap()).collect();
-    nums.into()
+fn load(input: Option<&String>) -> Result<Data, ParseError> {
+    let parser = match input {
+        Some(input) => Parser::try_open(input)?,
+        None => Parser::for_day(Day05::DAY)?,
+    };
+    Day05::parse(parser)
 }
 
-fn parse_lines(mut lines: impl Iterator<Item = String>) -> Data {
-    let seeds: Vec<i64> = lines
-        .next()
-        .unwrap()
-        .split(':')
-        .last()
-        .unwrap()
-        .trim()
-        .split(' ')
-        .map(|num| num.trim().parse::<i64>().unwrap())
-        .collect();
-
-
-    let mut maps = Vec::new();
-
-    let mut last = HashSet::new();
-
-    lines.next().unwrap();
-
-    for line in lines {
-        if line.is_empty() {
-            maps.push(last.clone());
-            last.clear();
-        } else if line.chars().next().unwrap().is_digit(10) {
-            let b = parse_boundary(line);
-            last.insert(b);
-        }
-    }
-
-    if !last.is_empty() {
-        maps.push(last);
+/// Prints the seed-to-location map composed of all the maps of the almanac.
+fn table(args: &[String]) -> Result<(), ParseError> {
+    if args.len() > 1 {
+        usage();
     }
 
-    Data { seeds, maps }
+    let data = load(args.first())?;
+    println!("seeds -> locations (shift), other seeds keep their number");
+    print!("{}", data.seed_to_location());
+    Ok(())
 }
 
-
-fn find_location(seed: i64, maps: &Vec<HashSet<Boundary>>) -> (i64, Option<i64>) {
-    let mut id = seed;
-
-    let mut same_count = None;
-
-    for map in maps {
-        for boundary in map {
-            let offset = id - boundary.src;
-
-            if offset < 0 {
-                let count_to_start = -offset;
-                if let Some(b) = same_count {
-                    if count_to_start < b {
-                        same_count = Some(count_to_start);
-                    }
-                }
-            }
-
-            if offset >= 0 && offset < boundary.range {
-                id = boundary.dst + offset;
-
-                let count_till_end = boundary.range - offset;
-
-                if let Some(b) = same_count {
-                    if count_till_end < b {
-                        same_count = Some(count_till_end)
-                    }
-                } else {
-                    same_count = Some(count_till_end);
-                }
-                break;
-            }
-        }
+/// Prints the seeds whose locations are in `[start, end)`.
+fn seeds_for(args: &[String]) -> Result<(), ParseError> {
+    let (start, end) = match args {
+        [start, end, ..] if args.len() <= 3 => match (start.parse(), end.parse()) {
+            (Ok(start), Ok(end)) => (start, end),
+            _ => usage(),
+        },
+        _ => usage(),
+    };
+
+    let data = load(args.get(2))?;
+    for (from, to) in data.seed_to_location().preimage(start, end) {
+        println!("{}..{}", from, to);
     }
-
-    (id, same_count)
-}
-
-fn part1(data: &Data) -> i64 {
-    data.seeds.iter().map(|seed| find_location(*seed, &data.maps)).min().unwrap().0
-}
-
-fn part2(data: &Data) -> i64 {
-    data.seeds.chunks(2).map(|chunk| {
-        let start = chunk[0];
-        let len = chunk[1];
-        let mut lowest = None;
-        let mut i = start;
-        while i < start + len {
-            let (location, can_skip) = find_location(i, &data.maps);
-
-            if let Some(lowest_) = lowest {
-                if location < lowest_ {
-                    lowest = Some(location);
-                }
-            } else {
-                lowest = Some(location);
-            }
-
-            i += can_skip.unwrap_or(1);
-        }
-        lowest.unwrap()
-    })
-    .min()
-    .unwrap()
+    Ok(())
 }
 
-fn main() -> io::Result<()> {
-    let name = args().nth(1);
-
-    if let Some(name) = name {
-        let file = BufReader::new(File::open(name)?);
-        let parsed_lines = parse_lines(file.lines().flatten());
-
-        
-        println!("Part 1: {}", part1(&parsed_lines));
-        println!("Part 2: {}", part2(&parsed_lines));
-    } else {
-        return Err(Error::new(
-            io::ErrorKind::InvalidInput,
-            "input file not provided",
-        ));
+/// Besides running the day, the almanac can be inspected with `--table` and
+/// `--seeds-for`.
+fn main() {
+    let args: Vec<String> = args().skip(1).collect();
+    let res = match args.first().map(String::as_str) {
+        Some("--table") => table(&args[1..]),
+        Some("--seeds-for") => seeds_for(&args[1..]),
+        _ => return aoc05::DAY.main(),
+    };
+
+    if let Err(e) = res {
+        eprintln!("Error: {}", e);
+        exit(1);
     }
-
-    Ok(())
 }
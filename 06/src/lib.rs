@@ -0,0 +1,197 @@
+use num::{integer::Roots, Integer, Unsigned};
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day06>();
+
+/// The races as listed, and the single race of part 2 whose time and record
+/// are the listed numbers with the spaces removed.
+pub struct Races {
+    races: Vec<(u128, u128)>,
+    kerning: (u128, u128),
+}
+
+/// Parses the numbers of a line, each of them and joined, rejecting those
+/// above `max`.
+fn parse_line(
+    line: Option<String>,
+    name: &str,
+    index: usize,
+    max: u128,
+) -> Result<(Vec<u128>, u128), ParseError> {
+    let line =
+        line.ok_or_else(|| ParseError::malformed(index + 1, "", format!("missing {}", name)))?;
+    let numbers = line
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| ParseError::malformed(index + 1, &line, format!("expected {}:", name)))?;
+
+    let parse = |s: &str| match s.parse::<u128>() {
+        Ok(number) if number > max => Err(ParseError::malformed(
+            index + 1,
+            &line,
+            format!("{} is larger than {}", number, max),
+        )),
+        Ok(number) => Ok(number),
+        Err(e) => Err(ParseError::malformed(index + 1, &line, e)),
+    };
+    let separate = numbers
+        .split_whitespace()
+        .map(parse)
+        .collect::<Result<_, _>>()?;
+    let joined = parse(&numbers.replace(' ', ""))?;
+
+    Ok((separate, joined))
+}
+
+fn parse(mut lines: impl Iterator<Item = String>) -> Result<Races, ParseError> {
+    // `count_winning_holds` squares the times
+    let (times, time) = parse_line(lines.next(), "Time", 0, u64::MAX.into())?;
+    let (records, record) = parse_line(lines.next(), "Distance", 1, u128::MAX)?;
+
+    Ok(Races {
+        races: times.into_iter().zip(records).collect(),
+        kerning: (time, record),
+    })
+}
+
+/// The number of whole-millisecond holds that beat `record` in a race of
+/// `time` milliseconds, i.e. of the integers strictly between the roots of
+/// `x * (time - x) = record`. Exact for any unsigned integer type in which
+/// `time * time` fits, including big integers.
+pub fn count_winning_holds<T>(time: T, record: T) -> T
+where
+    T: Integer + Unsigned + Roots + Clone,
+{
+    let two = T::one() + T::one();
+    let four = two.clone() * two.clone();
+    let square = time.clone() * time.clone();
+    // no hold wins if `square <= 4 * record`, checked without computing
+    // `4 * record`, which may not fit
+    let (quarter, rest) = square.div_rem(&four);
+    let quarter = if rest.is_zero() {
+        quarter
+    } else {
+        quarter + T::one()
+    };
+    if record >= quarter {
+        return T::zero();
+    }
+    let bound = four * record.clone();
+
+    let wins = |x: &T| x.clone() * (time.clone() - x.clone()) > record;
+
+    // the integer square root rounds down, so this is the first winning
+    // hold or one below it
+    let mut low = (time.clone() - (square - bound).sqrt()) / two;
+    if !wins(&low) {
+        low = low + T::one();
+        if !wins(&low) {
+            return T::zero();
+        }
+    }
+
+    // holds are symmetric around time / 2
+    let high = time - low.clone();
+    high - low + T::one()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Answer = u128;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_all(parse)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        let holds = input
+            .races
+            .iter()
+            .map(|&(time, record)| count_winning_holds(time, record));
+        Some(holds.product())
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        let (time, record) = input.kerning;
+        Some(count_winning_holds(time, record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day06, EXAMPLE, part1 = 288, part2 = 71503);
+    }
+
+    fn brute_force(time: u64, record: u64) -> u64 {
+        (0..=time).filter(|x| x * (time - x) > record).count() as u64
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for time in 0..60 {
+            for record in 0..(time * time / 4 + 2) {
+                assert_eq!(
+                    count_winning_holds(time, record),
+                    brute_force(time, record),
+                    "time {}, record {}",
+                    time,
+                    record
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn beyond_float_precision() {
+        // the roots are 2^53 +- sqrt(2), which a float sqrt cannot tell
+        // apart from 2^53
+        let time: u128 = 1 << 54;
+        let record = (1 << 106) - 2;
+        assert_eq!(count_winning_holds(time, record), 3);
+        assert_eq!(count_winning_holds(time, record + 1), 1);
+        assert_eq!(count_winning_holds(time, record + 2), 0);
+    }
+
+    #[test]
+    fn largest_u128_races() {
+        let time = u128::from(u64::MAX);
+        assert_eq!(count_winning_holds(time, u128::MAX), 0);
+        assert_eq!(count_winning_holds(time, 0), time - 1);
+        assert_eq!(count_winning_holds(time, time * time / 4 - 1), 2);
+    }
+
+    #[test]
+    fn rejects_times_that_cannot_be_squared() {
+        let input = format!("Time: {}\nDistance: 1\n", u128::from(u64::MAX) + 1);
+        assert!(Day06::parse(Parser::from_str(&input)).is_err());
+    }
+
+    #[test]
+    fn big_integers() {
+        // x = 10^40 + k wins iff k^2 < 10^10
+        let middle = BigUint::from(10u8).pow(40);
+        let time = &middle * 2u8;
+        let record = middle.pow(2) - BigUint::from(10u8).pow(10);
+        assert_eq!(count_winning_holds(time, record), BigUint::from(199_999u32));
+    }
+}
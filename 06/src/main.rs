@@ -0,0 +1,3 @@
+fn main() {
+    aoc06::DAY.main();
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day07>();
+
+#[derive(Clone, Debug)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: i32,
+}
+
+impl Hand {
+    pub fn bid(&self) -> i32 {
+        self.bid
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cards.iter().try_for_each(|card| write!(f, "{}", card))
+    }
+}
+
+/// Reads `<cards> <bid>`. Which cards make a valid hand is up to the
+/// [`HandRules`], see [`HandRules::check`].
+impl TryFrom<String> for Hand {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (cards, bid) = value.trim().split_once(' ').ok_or("missing bid")?;
+        let cards: Vec<char> = cards.chars().collect();
+        let bid = bid
+            .trim()
+            .parse()
+            .map_err(|e| format!("{}: {:?}", e, bid))?;
+
+        Ok(Self { cards, bid })
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum Combination {
+    HighCard,
+    One,
+    Two,
+    Three,
+    FullHouse,
+    Four,
+    Five,
+}
+
+/// The hand types of Camel Cards by the sizes of the groups of equal cards,
+/// largest first.
+pub const CAMEL_TYPES: &[(&[usize], Combination)] = &[
+    (&[5], Combination::Five),
+    (&[4, 1], Combination::Four),
+    (&[3, 2], Combination::FullHouse),
+    (&[3, 1, 1], Combination::Three),
+    (&[2, 2, 1], Combination::Two),
+    (&[2, 1, 1, 1], Combination::One),
+    (&[1, 1, 1, 1, 1], Combination::HighCard),
+];
+
+/// How hands are ranked: first by their type, then card by card.
+#[derive(Debug, Clone, Copy)]
+pub struct HandRules {
+    /// The cards from the weakest to the strongest.
+    pub order: &'static str,
+    /// Cards that join whichever group makes the hand strongest.
+    pub wildcards: &'static str,
+    /// The type of each shape of a hand, see [`CAMEL_TYPES`].
+    pub types: &'static [(&'static [usize], Combination)],
+}
+
+/// The rules of part 1.
+pub const STANDARD: HandRules = HandRules {
+    order: "23456789TJQKA",
+    wildcards: "",
+    types: CAMEL_TYPES,
+};
+
+/// The rules of part 2, where `J` is a joker and the weakest card.
+pub const JOKERS: HandRules = HandRules {
+    order: "J23456789TQKA",
+    wildcards: "J",
+    types: CAMEL_TYPES,
+};
+
+impl HandRules {
+    fn card_value(&self, card: char) -> Result<usize, String> {
+        self.order
+            .find(card)
+            .ok_or_else(|| format!("unknown card {:?}", card))
+    }
+
+    /// The type of `cards`. Wildcards always join the largest group, which
+    /// is never worse than any other choice.
+    pub fn combination(&self, cards: &[char]) -> Result<Combination, String> {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        let mut wildcards = 0;
+        for &card in cards {
+            if self.wildcards.contains(card) {
+                wildcards += 1;
+            } else {
+                *counts.entry(card).or_default() += 1;
+            }
+        }
+
+        let mut shape: Vec<usize> = counts.into_values().collect();
+        shape.sort_unstable_by(|a, b| b.cmp(a));
+        match shape.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => shape.push(wildcards),
+        }
+
+        self.types
+            .iter()
+            .find(|(s, _)| *s == shape.as_slice())
+            .map(|&(_, combination)| combination)
+            .ok_or_else(|| format!("no hand type for groups {:?}", shape))
+    }
+
+    /// What hands are ordered by: their type, then the values of their
+    /// cards in order.
+    fn key(&self, hand: &Hand) -> Result<(Combination, Vec<usize>), String> {
+        let values = hand
+            .cards
+            .iter()
+            .map(|&card| self.card_value(card))
+            .collect::<Result<_, _>>()?;
+        Ok((self.combination(&hand.cards)?, values))
+    }
+
+    /// Fails if `hand` has a card outside of [`HandRules::order`] or a shape
+    /// missing from [`HandRules::types`].
+    pub fn check(&self, hand: &Hand) -> Result<(), String> {
+        self.key(hand).map(|_| ())
+    }
+
+    /// The hands from the weakest to the strongest, with how they were
+    /// ranked.
+    pub fn rank<'a>(&self, hands: &'a [Hand]) -> Result<Vec<Ranked<'a>>, String> {
+        let mut keyed = hands
+            .iter()
+            .map(|hand| Ok((self.key(hand)?, hand)))
+            .collect::<Result<Vec<_>, String>>()?;
+        keyed.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        let plain = HandRules {
+            wildcards: "",
+            ..*self
+        };
+        keyed
+            .into_iter()
+            .zip(1..)
+            .map(|(((combination, _), hand), rank)| {
+                Ok(Ranked {
+                    hand,
+                    original: plain.combination(&hand.cards)?,
+                    combination,
+                    rank,
+                    winnings: hand.bid * rank,
+                })
+            })
+            .collect()
+    }
+
+    /// The sum of the bids multiplied by the ranks of the hands.
+    pub fn winnings(&self, hands: &[Hand]) -> Result<i32, String> {
+        Ok(self.rank(hands)?.iter().map(|r| r.winnings).sum())
+    }
+}
+
+/// A hand as ranked by [`HandRules::rank`].
+#[derive(Debug, Clone)]
+pub struct Ranked<'a> {
+    pub hand: &'a Hand,
+    /// The type of the hand with the wildcards taken at face value.
+    pub original: Combination,
+    pub combination: Combination,
+    pub rank: i32,
+    pub winnings: i32,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+    type Answer = i32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_by_lines(|line| {
+            let hand = Hand::try_from(line)?;
+            STANDARD.check(&hand)?;
+            JOKERS.check(&hand)?;
+            Ok::<_, String>(hand)
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        // the hands were checked against both rules by `parse`
+        STANDARD.winnings(input).ok()
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        JOKERS.winnings(input).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day07, EXAMPLE, part1 = 6440, part2 = 5905);
+    }
+
+    #[test]
+    fn variant_rules() {
+        let deuces = HandRules {
+            order: "2J3456789TQKA",
+            wildcards: "2J",
+            ..JOKERS
+        };
+        let cards = |s: &str| s.chars().collect::<Vec<_>>();
+
+        assert_eq!(JOKERS.combination(&cards("JJJJJ")), Ok(Combination::Five));
+        assert_eq!(
+            JOKERS.combination(&cards("22J33")),
+            Ok(Combination::FullHouse)
+        );
+        assert_eq!(deuces.combination(&cards("22J33")), Ok(Combination::Five));
+        assert_eq!(deuces.combination(&cards("2KQ9T")), Ok(Combination::One));
+    }
+
+    #[test]
+    fn other_decks() {
+        // a deck with a Zero below the 2 and hands of three cards
+        let small = HandRules {
+            order: "023456789TJQKA",
+            wildcards: "",
+            types: &[
+                (&[3], Combination::Five),
+                (&[2, 1], Combination::One),
+                (&[1, 1, 1], Combination::HighCard),
+            ],
+        };
+        let hands = ["0A0 3", "AK0 5", "AAA 7"]
+            .map(|line| Hand::try_from(String::from(line)).unwrap())
+            .to_vec();
+        assert_eq!(small.winnings(&hands), Ok(3 * 2 + 5 + 7 * 3));
+
+        assert!(STANDARD.check(&hands[0]).is_err());
+        assert!(STANDARD.winnings(&hands).is_err());
+        assert!(Day07::parse(Parser::from_str("0A0AA 3\n")).is_err());
+        assert!(Day07::parse(Parser::from_str("AKQJT 3\nAKQJ 3\n")).is_err());
+    }
+}
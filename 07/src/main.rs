@@ -1,187 +1,122 @@
-use std::{
-    collections::{HashMap, HashSet},
-    io::{self},
-};
+use std::{env::args, process::exit};
 
-use rust_base::aoc::Parser;
+use aoc07::{Day07, HandRules, JOKERS, STANDARD};
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Part,
+    solution::Solution,
+};
 
-#[derive(Clone, Debug)]
-struct Hand {
-    cards: Vec<char>,
-    bid: i32,
-}
+const USAGE: &str = "usage: aoc07 [input] [--part <1|2>] ...
+       aoc07 --explain [input] [--part <1|2>] [--csv]";
 
-impl From<String> for Hand {
-    fn from(value: String) -> Self {
-        let splitted: Vec<&str> = value.split(' ').collect();
-        let cards: Vec<char> = splitted[0].trim().chars().collect();
-        let bid: i32 = splitted[1].trim().parse().unwrap();
+const HEADER: [&str; 6] = ["rank", "hand", "bid", "type", "with jokers", "winnings"];
 
-        Self { cards, bid }
-    }
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(2)
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-enum Combination {
-    HighCard,
-    One,
-    Two,
-    Three,
-    FullHouse,
-    Four,
-    Five,
+/// The ranked hands of one part, one row per hand.
+fn rows(
+    rules: &HandRules,
+    parsed: &<Day07 as Solution>::Input,
+) -> Result<Vec<[String; 6]>, String> {
+    let ranked = rules.rank(parsed)?;
+    let rows = ranked
+        .into_iter()
+        .map(|r| {
+            [
+                r.rank.to_string(),
+                r.hand.to_string(),
+                r.hand.bid().to_string(),
+                format!("{:?}", r.original),
+                format!("{:?}", r.combination),
+                r.winnings.to_string(),
+            ]
+        })
+        .collect();
+    Ok(rows)
 }
 
-impl From<i32> for Combination {
-    fn from(value: i32) -> Self {
-        match value {
-            1 => Self::HighCard,
-            2 => Self::One,
-            3 => Self::Three,
-            4 => Self::Two,
-            5 => Self::Five,
-            6 => Self::FullHouse,
-            v => panic!("cannot convert from {}", v),
+fn print_table(rows: &[[String; 6]]) {
+    let mut widths = HEADER.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
         }
     }
-}
-
-fn get_combination(card: HashMap<char, i32>) -> Combination {
-    if card.values().any(|value| *value == 4) {
-        return Combination::Four;
-    }
-
-    card.values().fold(1, |acc, e| ((acc) * (*e))).into()
-}
-
-fn occurences_map(cards: Vec<char>) -> HashMap<char, i32> {
-    let mut map: HashMap<char, i32> = HashMap::new();
 
-    for card in cards {
-        let c = *map.get(&card).unwrap_or(&0) + 1;
-        map.insert(card, c);
+    let header = HEADER.map(String::from);
+    for row in std::iter::once(&header).chain(rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  "));
     }
-
-    map
 }
 
-fn card_value(card: char) -> i32 {
-    match card {
-        'A' => 100,
-        'K' => 90,
-        'Q' => 80,
-        'J' => 70,
-        'T' => 60,
-        '*' => -1,
-        v => v.to_digit(10).unwrap() as i32,
+/// Prints every hand with its types, rank and winnings, as a table or CSV.
+fn explain(args: &[String]) -> Result<(), ParseError> {
+    let mut input = None;
+    let mut only = None;
+    let mut csv = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--csv" => csv = true,
+            "--part" => match args.next().map(|p| p.parse::<Part>()) {
+                Some(Ok(part)) => only = Some(part),
+                _ => usage(),
+            },
+            _ if input.is_none() => input = Some(arg),
+            _ => usage(),
+        }
     }
-}
-
-fn compare_hands(left: &Hand, right: &Hand) -> std::cmp::Ordering {
-    let comb_left = get_combination(occurences_map(left.cards.clone()));
-    let comb_right = get_combination(occurences_map(right.cards.clone()));
-    compare_combinations(
-        comb_left,
-        comb_right,
-        left.cards.clone(),
-        right.cards.clone(),
-        false,
-    )
-}
 
-fn compare_combinations(
-    left: Combination,
-    right: Combination,
-    left_cards: Vec<char>,
-    right_cards: Vec<char>,
-    map_joker: bool,
-) -> std::cmp::Ordering {
-    if left < right {
-        return std::cmp::Ordering::Less;
-    }
+    let parser = match input {
+        Some(input) => Parser::try_open(input)?,
+        None => Parser::for_day(Day07::DAY)?,
+    };
+    let parsed = Day07::parse(parser)?;
 
-    if left > right {
-        return std::cmp::Ordering::Greater;
+    if csv {
+        println!("part,{}", HEADER.join(","));
     }
-
-    for (c1, c2) in left_cards.into_iter().zip(right_cards) {
-        let mut c1_ = c1;
-        let mut c2_ = c2;
-
-        if map_joker && c1_ == 'J' {
-            c1_ = '*';
-        }
-        if map_joker && c2_ == 'J' {
-            c2_ = '*';
-        }
-
-        if card_value(c1_) < card_value(c2_) {
-            return std::cmp::Ordering::Less;
-        }
-
-        if card_value(c1_) > card_value(c2_) {
-            return std::cmp::Ordering::Greater;
+    for (part, rules) in [(Part::One, STANDARD), (Part::Two, JOKERS)] {
+        if !part.selected(only) {
+            continue;
         }
-    }
-
-    std::cmp::Ordering::Equal
-}
-
-fn find_best_combination(mut cards: Vec<char>) -> Combination {
-    let different_cards: HashSet<char> = HashSet::from_iter(cards.clone());
-
-    let next_joker = cards.iter().zip(0..).find(|(card, _)| **card == 'J');
-    if let Some((_, index)) = next_joker {
-        let mut best_combination = get_combination(occurences_map(cards.clone()));
-
-        for possible in different_cards {
-            if possible == 'J' {
-                continue;
-            }
 
-            cards[index] = possible;
-            let next_combination = find_best_combination(cards.clone());
-            if next_combination > best_combination {
-                best_combination = next_combination;
+        let invalid = |reason| ParseError::InvalidInput { reason };
+        let rows = rows(&rules, &parsed).map_err(invalid)?;
+        if csv {
+            let number = if part == Part::One { 1 } else { 2 };
+            for row in rows {
+                println!("{},{}", number, row.join(","));
             }
+        } else {
+            println!("{}", part);
+            print_table(&rows);
+            let winnings = rules.winnings(&parsed).map_err(invalid)?;
+            println!("Total winnings: {}", winnings);
         }
-
-        cards[index] = 'J';
-        return best_combination;
     }
-
-    get_combination(occurences_map(cards))
-}
-
-fn compare_hands_joker(left: &Hand, right: &Hand) -> std::cmp::Ordering {
-    let comb_left = find_best_combination(left.cards.clone());
-    let comb_right = find_best_combination(right.cards.clone());
-
-    compare_combinations(
-        comb_left,
-        comb_right,
-        left.cards.clone(),
-        right.cards.clone(),
-        true,
-    )
+    Ok(())
 }
 
-fn part(p: i32, mut hands: Vec<Hand>) -> i32 {
-    if p == 1 {
-        hands.sort_by(compare_hands);
-    } else if p == 2 {
-        hands.sort_by(compare_hands_joker);
-    } else {
-        panic!("unknown part {}", p);
+/// Besides running the day, `--explain` shows how the hands were ranked.
+fn main() {
+    let args: Vec<String> = args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("--explain") {
+        return aoc07::DAY.main();
     }
 
-    hands.into_iter().zip(1..).map(|(h, ord)| h.bid * ord).sum()
-}
-
-fn main() -> io::Result<()> {
-    let parser = Parser::new();
-    let parsed_lines = parser.parse_by_lines::<Hand>(|v| v.into());
-    println!("Part 1: {}", part(1, parsed_lines.clone()));
-    println!("Part 2: {}", part(2, parsed_lines));
-    Ok(())
+    if let Err(e) = explain(&args[1..]) {
+        eprintln!("Error: {}", e);
+        exit(1);
+    }
 }
@@ -0,0 +1,195 @@
+use num::integer::lcm;
+use regex::Regex;
+use std::collections::HashMap;
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day08>();
+
+#[derive(Debug, Clone)]
+pub struct Component {
+    left: String,
+    right: String,
+}
+
+fn parse(
+    lines: impl Iterator<Item = String>,
+) -> Result<(String, HashMap<String, Component>), ParseError> {
+    let mut lines = lines.enumerate().map(|(i, l)| (i + 1, l));
+
+    let directions = match lines.next() {
+        Some((_, l)) if !l.trim().is_empty() => String::from(l.trim()),
+        _ => return Err(ParseError::malformed(1, "", "missing directions")),
+    };
+    if let Some(direction) = directions.chars().find(|&d| d != 'L' && d != 'R') {
+        let reason = format!("unknown direction {:?}", direction);
+        return Err(ParseError::malformed(1, &directions, reason));
+    }
+
+    if let Some((i, l)) = lines.next().filter(|(_, l)| !l.trim().is_empty()) {
+        return Err(ParseError::malformed(i, &l, "expected an empty line"));
+    }
+
+    let mut components = HashMap::new();
+    let mut references = Vec::new();
+
+    let re = Regex::new(r"^(\w+) = \((\w+), (\w+)\)$").unwrap();
+
+    for (i, l) in lines {
+        let res = re
+            .captures(l.trim())
+            .ok_or_else(|| ParseError::malformed(i, &l, "expected \"AAA = (BBB, CCC)\""))?;
+        let component = Component {
+            left: String::from(&res[2]),
+            right: String::from(&res[3]),
+        };
+        references.push((i, l.clone(), component.clone()));
+        components.insert(String::from(&res[1]), component);
+    }
+
+    for (i, l, component) in references {
+        for name in [&component.left, &component.right] {
+            if !components.contains_key(name) {
+                return Err(ParseError::malformed(
+                    i,
+                    &l,
+                    format!("unknown node {:?}", name),
+                ));
+            }
+        }
+    }
+
+    Ok((directions, components))
+}
+
+fn part1(
+    directions: &str,
+    map: &HashMap<String, Component>,
+    start: &str,
+    mut end_predicate: impl FnMut(&str, i32) -> bool,
+) -> i32 {
+    let mut cur = start;
+    let mut steps = 0;
+    for direction in directions.chars().cycle() {
+        if end_predicate(cur, steps) {
+            break;
+        }
+
+        steps += 1;
+        if direction == 'R' {
+            cur = &map[cur].right;
+        } else {
+            cur = &map[cur].left;
+        }
+    }
+
+    steps
+}
+
+fn ends_with(s: &str, c: char) -> bool {
+    s.chars().next_back().unwrap() == c
+}
+
+fn part2(directions: &str, map: &HashMap<String, Component>) -> i64 {
+    let mut starts = Vec::new();
+    for name in map.keys() {
+        if ends_with(name, 'A') {
+            starts.push(name);
+        }
+    }
+
+    let mut final_ = 1i64;
+    for start in starts {
+        let len = directions.len();
+        let res = part1(directions, map, start, |v, steps| {
+            steps % len as i32 == 0 && ends_with(v, 'Z')
+        }) as i64;
+        final_ = lcm(res, final_);
+    }
+
+    final_
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (String, HashMap<String, Component>);
+    type Answer = i64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_all(parse)
+    }
+
+    /// `None` for maps without an `AAA` node, like the example of part 2.
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        let (directions, map) = input;
+        if !map.contains_key("AAA") {
+            return None;
+        }
+        Some(part1(directions, map, "AAA", |v, _| v == "ZZZ") as i64)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        let (directions, map) = input;
+        Some(part2(directions, map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn examples() {
+        aoc_example!(Day08, EXAMPLE_1, part1 = 2);
+        aoc_example!(Day08, EXAMPLE_2, part1 = 6);
+        aoc_example!(Day08, EXAMPLE_3, part2 = 6);
+    }
+
+    #[test]
+    fn no_part1_without_aaa() {
+        let input = Day08::parse(Parser::from_str(EXAMPLE_3)).unwrap();
+        assert_eq!(Day08::part1(&input), None);
+    }
+}
@@ -0,0 +1,82 @@
+use std::num::ParseIntError;
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day09>();
+
+fn reduce(sequence: &[i32]) -> Vec<i32> {
+    let mut next = Vec::new();
+    for (i, num) in sequence.iter().enumerate().skip(1) {
+        next.push(*num - sequence[i - 1]);
+    }
+    next
+}
+
+fn compute_next(sequence: &[i32]) -> i32 {
+    if sequence.iter().all(|v| v == &0) {
+        return 0;
+    }
+    sequence.last().unwrap() + compute_next(&reduce(sequence))
+}
+
+fn compute_prev(sequence: &[i32]) -> i32 {
+    if sequence.iter().all(|v| v == &0) {
+        return 0;
+    }
+    sequence[0] - compute_prev(&reduce(sequence))
+}
+
+fn part1(sequences: &[Vec<i32>]) -> i32 {
+    sequences.iter().map(|s| compute_next(s.as_slice())).sum()
+}
+
+fn part2(sequences: &[Vec<i32>]) -> i32 {
+    sequences.iter().map(|s| compute_prev(s.as_slice())).sum()
+}
+
+fn parse(line: String) -> Result<Vec<i32>, ParseIntError> {
+    line.split(' ').map(|part| part.trim().parse()).collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_by_lines(parse)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day09, EXAMPLE, part1 = 114, part2 = 2);
+    }
+}
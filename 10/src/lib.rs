@@ -0,0 +1,232 @@
+use std::collections::{HashMap, HashSet};
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    geometry::{
+        Direction::{East, North, South, West},
+        Point,
+    },
+    grid::Grid,
+    runner::Day,
+    search::{bfs, flood_fill, Bfs},
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day10>();
+
+type Coord = Point<i32>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pipe {
+    Ground,
+    Start,
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NorthEast),
+            'J' => Ok(Self::NorthWest),
+            '7' => Ok(Self::SouthWest),
+            'F' => Ok(Self::SouthEast),
+            ch => Err(format!("unknown pipe type {}", ch)),
+        }
+    }
+}
+
+fn find_pipes(grid: &Grid<Pipe>) -> HashMap<Coord, Pipe> {
+    grid.iter()
+        .filter(|(_, pipe)| **pipe != Pipe::Ground)
+        .map(|((row, col), pipe)| (Point::new(row as i32, col as i32), *pipe))
+        .collect()
+}
+
+fn find_start(grid: &Grid<Pipe>) -> Result<Coord, ParseError> {
+    let mut starts = grid.iter().filter(|(_, pipe)| **pipe == Pipe::Start);
+    let ((row, col), _) = starts.next().ok_or_else(|| ParseError::InvalidInput {
+        reason: String::from("missing start S"),
+    })?;
+    if let Some(((row, col), _)) = starts.next() {
+        return Err(ParseError::InvalidCell {
+            row,
+            col,
+            ch: 'S',
+            reason: String::from("second start"),
+        });
+    }
+
+    Ok(Point::new(row as i32, col as i32))
+}
+
+/// The cells `pipe` at `from` points to; the start may connect anywhere.
+fn ends(from: Coord, pipe: Pipe) -> Vec<Coord> {
+    let [up, down, left, right] = [North, South, West, East].map(|d| from.step(d));
+    match pipe {
+        Pipe::Ground => vec![],
+        Pipe::Start => vec![up, down, left, right],
+        Pipe::Vertical => vec![up, down],
+        Pipe::Horizontal => vec![left, right],
+        Pipe::NorthEast => vec![up, right],
+        Pipe::NorthWest => vec![up, left],
+        Pipe::SouthWest => vec![left, down],
+        Pipe::SouthEast => vec![right, down],
+    }
+}
+
+/// The pipes connected to the one at `from`, i.e. those pointing back to it.
+fn neighbors(from: Coord, pipes: &HashMap<Coord, Pipe>) -> Vec<Coord> {
+    ends(from, pipes[&from])
+        .into_iter()
+        .filter(|n| {
+            pipes
+                .get(n)
+                .is_some_and(|&pipe| ends(*n, pipe).contains(&from))
+        })
+        .collect()
+}
+
+fn traverse(
+    start: Coord,
+    pipes: &HashMap<Coord, Pipe>,
+) -> Bfs<Coord, impl FnMut(&Coord) -> Vec<Coord> + '_> {
+    bfs(start, |current: &Coord| neighbors(*current, pipes))
+}
+
+fn part1(start: Coord, pipes: &HashMap<Coord, Pipe>) -> usize {
+    traverse(start, pipes)
+        .map(|(_, distance, _)| distance)
+        .max()
+        .unwrap()
+}
+
+/// Draws the loop at twice the resolution, so that the gaps between
+/// neighbouring pipes become cells of their own, with an empty border around.
+fn extend(route: &HashSet<Coord>, pipes: &HashMap<Coord, Pipe>) -> Grid<bool> {
+    let height = route.iter().map(|p| p.row).max().unwrap_or(0) as usize + 1;
+    let width = route.iter().map(|p| p.col).max().unwrap_or(0) as usize + 1;
+    let mut extended_route = Grid::new(vec![vec![false; 2 * width + 1]; 2 * height + 1]);
+
+    for coord in route {
+        let center = *coord * 2 + Point::new(1, 1);
+        for neighbor in neighbors(*coord, pipes) {
+            for p in [center, center + (neighbor - *coord)] {
+                extended_route[(p.row as usize, p.col as usize)] = true;
+            }
+        }
+    }
+
+    extended_route
+}
+
+fn part2(start: Coord, pipes: &HashMap<Coord, Pipe>) -> usize {
+    let route = traverse(start, pipes).map(|(coord, _, _)| coord).collect();
+    let extended_route = extend(&route, pipes);
+    let outside = flood_fill(&extended_route, (0, 0), |on_route| !on_route);
+
+    extended_route
+        .iter()
+        .filter(|((row, col), on_route)| {
+            row % 2 == 1 && col % 2 == 1 && !**on_route && !outside[(*row, *col)]
+        })
+        .count()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = (Coord, HashMap<Coord, Pipe>);
+    type Answer = usize;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        let grid = parser.try_parse_grid()?;
+        Ok((find_start(&grid)?, find_pipes(&grid)))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        let (start, pipes) = input;
+        Some(part1(*start, pipes))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        let (start, pipes) = input;
+        Some(part2(*start, pipes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const EXAMPLE_2: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+    const EXAMPLE_3: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn examples() {
+        aoc_example!(Day10, EXAMPLE_1, part1 = 8);
+        aoc_example!(Day10, EXAMPLE_2, part2 = 4);
+        aoc_example!(Day10, EXAMPLE_3, part2 = 10);
+    }
+
+    #[test]
+    fn invalid_maps() {
+        let parse = |input| Day10::parse(Parser::from_str(input));
+        assert!(matches!(
+            parse(".|.\n.|.\n"),
+            Err(ParseError::InvalidInput { .. })
+        ));
+        assert!(matches!(
+            parse("S-7\n|xJ\n"),
+            Err(ParseError::InvalidCell { row: 1, col: 1, .. })
+        ));
+        assert!(matches!(
+            parse("SS\n..\n"),
+            Err(ParseError::InvalidCell { row: 0, col: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::{HashMap, HashSet};
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    geometry::Point,
+    grid::Grid,
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day11>();
+
+type Coord = Point<i64>;
+
+type Planets = (Vec<Coord>, HashSet<i64>, HashSet<i64>);
+
+fn find_planets(grid: &Grid<char>) -> Vec<Coord> {
+    grid.iter()
+        .filter(|(_, ch)| **ch == '#')
+        .map(|((row, col), _)| Point::new(row as i64, col as i64))
+        .collect()
+}
+
+fn with_occupied(planets: Vec<Coord>) -> Planets {
+    let occupied_rows = planets.iter().map(|p| p.row).collect();
+    let occupied_cols = planets.iter().map(|p| p.col).collect();
+
+    (planets, occupied_rows, occupied_cols)
+}
+
+fn real_planet_coord(
+    planet: Coord,
+    occupied_rows: &HashSet<i64>,
+    occupied_cols: &HashSet<i64>,
+    scale: i64,
+) -> Coord {
+    let mut offs = Vec::new();
+    for (coord, set) in [planet.row, planet.col]
+        .iter()
+        .zip([occupied_rows, occupied_cols])
+    {
+        offs.push((scale - 1) * (0..=*coord).filter(|v| !set.contains(v)).count() as i64)
+    }
+
+    planet + Point::new(offs[0], offs[1])
+}
+
+fn part(planets: &Planets, scale: i64) -> i64 {
+    let (planets, occupied_rows, occupied_cols) = planets;
+
+    let mut coords_cache = HashMap::new();
+
+    let mut res = 0;
+    let max = planets.len();
+    for i in 0..max {
+        for j in i..max {
+            let mut coords = Vec::new();
+
+            for coord in [planets[i], planets[j]] {
+                if let Some(coord) = coords_cache.get(&coord) {
+                    coords.push(*coord);
+                } else {
+                    let computed = real_planet_coord(coord, occupied_rows, occupied_cols, scale);
+                    // verify that nothing is computed twice
+                    let prev = coords_cache.insert(coord, computed);
+                    assert_eq!(prev, None);
+                    coords.push(computed);
+                }
+            }
+
+            let dist = coords[0].manhattan(coords[1]);
+            res += dist;
+        }
+    }
+
+    res
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Planets;
+    type Answer = i64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        Ok(with_occupied(find_planets(&parser.try_parse_grid()?)))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part(input, 2))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part(input, 1_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day11, EXAMPLE, part1 = 374, part2 = 82000210);
+    }
+
+    #[test]
+    fn example_scales() {
+        let planets = Day11::parse(Parser::from_str(EXAMPLE)).unwrap();
+        assert_eq!(part(&planets, 10), 1030);
+        assert_eq!(part(&planets, 100), 8410);
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day12>();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordType {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+type Row = (Vec<RecordType>, Vec<i32>);
+
+impl TryFrom<char> for RecordType {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(RecordType::Operational),
+            '#' => Ok(RecordType::Damaged),
+            '?' => Ok(RecordType::Unknown),
+            ch => Err(format!("unknown type {:?}", ch)),
+        }
+    }
+}
+
+/// Counts the arrangements with a dynamic program over the springs: after
+/// each spring, `states` maps (finished groups, length of the current run of
+/// damaged springs) to the number of ways to get there. Relies on the row
+/// ending with an operational spring, which closes the last run.
+fn count_arrangements(row: &Row) -> u64 {
+    let (springs, lengths) = row;
+
+    let mut states: HashMap<(usize, i32), u64> = HashMap::from([((0, 0), 1)]);
+    for spring in springs {
+        let mut next = HashMap::new();
+        for (&(group, run), &count) in &states {
+            if *spring != RecordType::Operational && lengths.get(group).is_some_and(|&l| run < l) {
+                *next.entry((group, run + 1)).or_default() += count;
+            }
+
+            if *spring != RecordType::Damaged {
+                if run == 0 {
+                    *next.entry((group, 0)).or_default() += count;
+                } else if lengths[group] == run {
+                    *next.entry((group + 1, 0)).or_default() += count;
+                }
+            }
+        }
+        states = next;
+    }
+
+    states.get(&(lengths.len(), 0)).copied().unwrap_or(0)
+}
+
+/// Repeats the springs five times joined by unknown springs, and the lengths
+/// five times.
+fn unfold(row: &Row) -> Row {
+    let (springs, lengths) = row;
+    // without the operational spring added by `parse_row`
+    let springs = &springs[..springs.len() - 1];
+
+    let mut unfolded = springs.to_vec();
+    for _ in 1..5 {
+        unfolded.push(RecordType::Unknown);
+        unfolded.extend_from_slice(springs);
+    }
+    unfolded.push(RecordType::Operational);
+
+    (unfolded, lengths.repeat(5))
+}
+
+fn parse_row(l: String) -> Result<Row, String> {
+    let (springs, lengths) = l.split_once(' ').ok_or("missing group lengths")?;
+    let mut springs: Vec<RecordType> = springs
+        .chars()
+        .map(RecordType::try_from)
+        .collect::<Result<_, _>>()?;
+    springs.push(RecordType::Operational);
+    let lengths: Vec<i32> = lengths
+        .split(',')
+        .map(|num| match num.trim().parse() {
+            Ok(length) if length > 0 => Ok(length),
+            Ok(_) => Err(format!("group lengths must be positive: {:?}", num)),
+            Err(e) => Err(format!("{}: {:?}", e, num)),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((springs, lengths))
+}
+
+fn part1(data: &[Row]) -> u64 {
+    data.iter().map(count_arrangements).sum()
+}
+
+fn part2(data: &[Row]) -> u64 {
+    data.iter()
+        .map(|row| count_arrangements(&unfold(row)))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Answer = u64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_by_lines(parse_row)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day12, EXAMPLE, part1 = 21, part2 = 525152);
+    }
+}
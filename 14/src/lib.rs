@@ -0,0 +1,140 @@
+use crate::MapElem::{CubeRock, RoundedRock, Void};
+use rust_base::{
+    aoc::{ParseError, Parser},
+    cycle::find_cycle_brent,
+    geometry::Direction::{self, East, North, South, West},
+    grid::{Grid, GridViewMut},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day14>();
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MapElem {
+    RoundedRock,
+    CubeRock,
+    Void,
+}
+
+type Map = Grid<MapElem>;
+
+impl TryFrom<char> for MapElem {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(RoundedRock),
+            '#' => Ok(CubeRock),
+            '.' => Ok(Void),
+            ch => Err(format!("cannot convert {} to element", ch)),
+        }
+    }
+}
+
+fn facing(map: &mut Map, direction: Direction) -> GridViewMut<'_, MapElem> {
+    let view = map.view_mut();
+    match direction {
+        North => view,
+        West => view.rotate_cw(),
+        South => view.rotate_cw().rotate_cw(),
+        East => view.rotate_ccw(),
+    }
+}
+
+fn find_cost(map: &Map) -> usize {
+    map.rows()
+        .rev()
+        .enumerate()
+        .map(|(i, row)| (i + 1) * row.iter().filter(|e| e == &&RoundedRock).count())
+        .sum()
+}
+
+/// Rolls all rounded rocks to the top of the view.
+fn tilt(mut map: GridViewMut<MapElem>) {
+    for col in 0..map.width() {
+        let mut free = 0;
+        for row in 0..map.height() {
+            match map[(row, col)] {
+                CubeRock => free = row + 1,
+                RoundedRock => {
+                    map.swap((free, col), (row, col));
+                    free += 1;
+                }
+                Void => {}
+            }
+        }
+    }
+}
+
+fn part1(map: &Map) -> usize {
+    let mut map = map.clone();
+    tilt(facing(&mut map, North));
+    find_cost(&map)
+}
+
+fn spin(map: &Map) -> Map {
+    let mut res = map.clone();
+    for dir in [North, West, South, East] {
+        tilt(facing(&mut res, dir));
+    }
+    res
+}
+
+fn part2(map: &Map) -> usize {
+    const ITERS: usize = 1000000000;
+
+    let cycle = find_cycle_brent(map.clone(), spin);
+    find_cost(&cycle.state_at(map.clone(), spin, ITERS))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Map;
+    type Answer = usize;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_grid()
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day14, EXAMPLE, part1 = 136, part2 = 64);
+    }
+
+    #[test]
+    fn own() {
+        aoc_example!(Day14, include_str!("../own.txt"), part1 = 12, part2 = 11);
+    }
+}
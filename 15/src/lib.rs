@@ -0,0 +1,131 @@
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day15>();
+
+fn parse(mut lines: impl Iterator<Item = String>) -> Result<Vec<String>, ParseError> {
+    let line = lines
+        .next()
+        .filter(|line| !line.is_empty())
+        .ok_or_else(|| ParseError::malformed(1, "", "missing initialization sequence"))?;
+
+    for step in line.split(',') {
+        let valid = match step.split_once('=') {
+            Some((label, focal_length)) => !label.is_empty() && focal_length.parse::<u32>().is_ok(),
+            None => step.len() > 1 && step.ends_with('-'),
+        };
+        if !valid {
+            let reason = format!("expected \"label=N\" or \"label-\", found {:?}", step);
+            return Err(ParseError::malformed(1, &line, reason));
+        }
+    }
+
+    Ok(line.split(',').map(String::from).collect())
+}
+
+fn hash(data: &str) -> u32 {
+    let mut cur = 0;
+
+    for ch in data.chars() {
+        cur += ch as u32;
+        cur *= 17;
+        cur %= 256;
+    }
+
+    cur
+}
+
+fn part1(instructions: &[String]) -> u32 {
+    instructions.iter().map(|i| hash(i)).sum()
+}
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    focal_length: u32,
+}
+
+fn part2(instructions: &[String]) -> u32 {
+    let mut map = vec![Vec::<Lens>::new(); 256];
+
+    for instruction in instructions {
+        let label;
+        let mut fl = None;
+        if instruction.contains('=') {
+            let spl: Vec<&str> = instruction.split('=').collect();
+            label = String::from(spl[0]);
+            fl = spl[1].parse::<u32>().ok();
+        } else {
+            let mut cloned = instruction.clone();
+            cloned.pop();
+            label = cloned;
+        }
+
+        let h = hash(&label) as usize;
+        if let Some(focal_length) = fl {
+            if let Some(lens) = map[h]
+                .iter()
+                .enumerate()
+                .find(|(_i, l)| l.label == label)
+                .map(|v| v.0)
+            {
+                map[h][lens].focal_length = focal_length;
+            } else {
+                map[h].push(Lens {
+                    label,
+                    focal_length,
+                });
+            }
+        } else {
+            map[h].retain(|l| l.label != label);
+        }
+    }
+
+    let mut res: u32 = 0;
+    for (box_num, box_) in map.iter().enumerate() {
+        for (lens_num, lens) in box_.iter().enumerate() {
+            res += (box_num + 1) as u32 * (lens_num + 1) as u32 * lens.focal_length;
+        }
+    }
+    res
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        parser.try_parse_all(parse)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day15, EXAMPLE, part1 = 1320, part2 = 145);
+    }
+}
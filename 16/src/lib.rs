@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use rust_base::{
+    aoc::{ParseError, Parser},
+    geometry::{
+        Direction::{self, East, North, South, West},
+        Point,
+    },
+    grid::Grid,
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day16>();
+
+pub struct Board {
+    board_: Grid<Block>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Block {
+    Void,
+    HorizontalSplit,
+    VerticalSplit,
+    ClockwiseTurn,
+    CounterClockwiseTurn,
+}
+
+impl TryFrom<char> for Block {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Void),
+            '-' => Ok(Self::HorizontalSplit),
+            '|' => Ok(Self::VerticalSplit),
+            '\\' => Ok(Self::CounterClockwiseTurn),
+            '/' => Ok(Self::ClockwiseTurn),
+            ch => Err(format!("unknown char {}", ch)),
+        }
+    }
+}
+
+impl Board {
+    fn new(parser: Parser) -> Result<Self, ParseError> {
+        Ok(Board {
+            board_: parser.try_parse_grid()?,
+        })
+    }
+
+    fn get(&self, pos: Pos) -> Option<Block> {
+        self.board_.get(pos.row, pos.col).copied()
+    }
+}
+
+type Pos = Point<i32>;
+
+fn traverse_from(
+    mut pos: Pos,
+    mut dir: Direction,
+    board: &Board,
+    visited: &mut HashSet<(Pos, Direction)>,
+) {
+    while let Some(block) = board.get(pos) {
+        if !visited.insert((pos, dir)) {
+            return;
+        };
+
+        match block {
+            Block::Void => {}
+            Block::HorizontalSplit => {
+                if !dir.is_horizontal() {
+                    traverse_from(pos.step(West), West, board, visited);
+                    traverse_from(pos.step(East), East, board, visited);
+                    return;
+                }
+            }
+            Block::VerticalSplit => {
+                if dir.is_horizontal() {
+                    traverse_from(pos.step(North), North, board, visited);
+                    traverse_from(pos.step(South), South, board, visited);
+                    return;
+                }
+            }
+            Block::ClockwiseTurn => {
+                // /
+                dir = if dir.is_horizontal() {
+                    dir.turn_left()
+                } else {
+                    dir.turn_right()
+                };
+            }
+            Block::CounterClockwiseTurn => {
+                // \
+                dir = if dir.is_horizontal() {
+                    dir.turn_right()
+                } else {
+                    dir.turn_left()
+                };
+            }
+        }
+        pos = pos.step(dir);
+    }
+}
+
+fn part1(board: &Board) -> usize {
+    let mut visited = HashSet::new();
+    traverse_from(Point::new(0, 0), East, board, &mut visited);
+    different_positions(visited)
+}
+
+fn different_positions(path: HashSet<(Pos, Direction)>) -> usize {
+    path.iter()
+        .map(|(pos, _dir)| *pos)
+        .collect::<HashSet<Pos>>()
+        .len()
+}
+
+fn part2(board: &Board) -> usize {
+    let mut max = 0;
+    for row in 0..board.board_.height() {
+        let mut visited = HashSet::new();
+        traverse_from(Point::new(row as i32, 0), East, board, &mut visited);
+        let mut visited_ = HashSet::new();
+        traverse_from(
+            Point::new(row as i32, (board.board_.width() - 1) as i32),
+            West,
+            board,
+            &mut visited_,
+        );
+        max = max
+            .max(different_positions(visited_))
+            .max(different_positions(visited));
+    }
+
+    for col in 0..board.board_.width() {
+        let mut visited = HashSet::new();
+        traverse_from(Point::new(0, col as i32), South, board, &mut visited);
+        let mut visited_ = HashSet::new();
+        traverse_from(
+            Point::new((board.board_.height() - 1) as i32, col as i32),
+            North,
+            board,
+            &mut visited_,
+        );
+        max = max
+            .max(different_positions(visited_))
+            .max(different_positions(visited));
+    }
+    max
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Board;
+    type Answer = usize;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        Board::new(parser)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day16, EXAMPLE, part1 = 46, part2 = 51);
+    }
+}
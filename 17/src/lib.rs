@@ -0,0 +1,151 @@
+use rust_base::{
+    aoc::{ParseError, Parser},
+    geometry::{Direction, Point},
+    grid::Grid,
+    runner::Day,
+    search::astar,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day17>();
+
+type Map = Grid<i32>;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+struct Crucible {
+    point: Point<i32>,
+    dir: Option<Direction>,
+    straight: i32,
+}
+
+impl Crucible {
+    fn moves(
+        &self,
+        map: &Map,
+        shortest_straight: i32,
+        longest_straight: i32,
+    ) -> Vec<(Crucible, i32)> {
+        let mut next = Vec::new();
+
+        for dir in Direction::ALL {
+            let straight = if Some(dir) == self.dir {
+                self.straight + 1
+            } else {
+                1
+            };
+
+            if Some(dir.opposite()) == self.dir
+                || straight > longest_straight
+                || (straight == 1 && self.dir.is_some() && self.straight < shortest_straight)
+            {
+                continue;
+            }
+
+            let point = self.point.step(dir);
+            if let Some(&cost) = map.get(point.row, point.col) {
+                next.push((
+                    Crucible {
+                        point,
+                        dir: Some(dir),
+                        straight,
+                    },
+                    cost,
+                ));
+            }
+        }
+
+        next
+    }
+}
+
+/// The least heat loss from the top-left to the bottom-right corner, if the
+/// crucible can get there at all.
+fn traverse(map: &Map, shortest_straight: i32, longest_straight: i32) -> Option<i32> {
+    let start = Crucible {
+        point: Point::new(0, 0),
+        dir: None,
+        straight: 0,
+    };
+    let end = Point::new(map.height() as i32 - 1, map.width() as i32 - 1);
+
+    astar(
+        start,
+        |cur| cur.moves(map, shortest_straight, longest_straight),
+        |cur| cur.point.manhattan(end),
+        |cur| cur.point == end && cur.straight >= shortest_straight,
+    )
+    .map(|path| path.cost)
+}
+
+fn part1(map: &Map) -> Option<i32> {
+    traverse(map, 0, 3)
+}
+
+/// `None` for maps too small for the ultra crucible to stop at the end.
+fn part2(map: &Map) -> Option<i32> {
+    traverse(map, 4, 10)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Map;
+    type Answer = i32;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        // a block losing no heat would make the Manhattan distance to the
+        // end overestimate the remaining loss, which `astar` must not do
+        let map = parser.try_parse_grid_with(|ch| match ch.to_digit(10) {
+            Some(0) => Err(String::from("heat loss must be at least 1")),
+            Some(d) => Ok(d as i32),
+            None => Err(format!("{} is not a digit", ch)),
+        })?;
+        if map.width() == 0 {
+            return Err(ParseError::malformed(1, "", "empty map"));
+        }
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Answer> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day17, EXAMPLE, part1 = 102, part2 = 94);
+    }
+
+    #[test]
+    fn test_file() {
+        aoc_example!(Day17, include_str!("../test.txt"), part1 = 59, part2 = 71);
+    }
+}
@@ -0,0 +1,139 @@
+use std::{env::args, path::PathBuf, process::exit};
+
+use rust_base::runner::{Day, Options};
+
+mod scaffold;
+
+const DAYS: &[Day] = &[
+    aoc01::DAY,
+    aoc02::DAY,
+    aoc03::DAY,
+    aoc04::DAY,
+    aoc05::DAY,
+    aoc06::DAY,
+    aoc07::DAY,
+    aoc08::DAY,
+    aoc9::DAY,
+    aoc10::DAY,
+    aoc11::DAY,
+    aoc12::DAY,
+    aoc14::DAY,
+    aoc15::DAY,
+    aoc16::DAY,
+    aoc17::DAY,
+];
+
+const USAGE: &str = "usage: aoc run <day> [input | --example <K>] [options]
+       aoc run --all [--example <K>] [options]
+       aoc new <day>
+
+Without an explicit input, day N reads inputs/NN.txt, or inputs/NN.exampleK.txt
+with --example K. AOC_INPUTS overrides the inputs directory.
+
+options:
+    --part <1|2>   run only one part
+    --bench <N>    time each phase over N runs
+    --check        compare the answers with answers.toml
+    --record       store the answers in answers.toml
+                   (both only with the day's own input)";
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(2)
+}
+
+fn run(mut args: impl Iterator<Item = String>) {
+    let mut all = false;
+    let mut day = None;
+    let mut input = None;
+    let mut example = None;
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        match options.parse_flag(&arg, &mut args) {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("{}", e);
+                usage()
+            }
+        }
+
+        match arg.as_str() {
+            "--all" => all = true,
+            "--example" => match args.next().map(|k| k.parse::<usize>()) {
+                Some(Ok(k)) => example = Some(k),
+                _ => usage(),
+            },
+            _ if day.is_none() => match arg.parse::<u8>() {
+                Ok(n) => day = Some(n),
+                Err(_) => usage(),
+            },
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => usage(),
+        }
+    }
+
+    let selected: Vec<&Day> = match (all, day) {
+        (true, None) => DAYS.iter().collect(),
+        (false, Some(n)) => match DAYS.iter().find(|d| d.number == n) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("day {} is not registered", n);
+                exit(2)
+            }
+        },
+        _ => usage(),
+    };
+
+    let mut failed = false;
+    for day in selected {
+        let input = match day.input(input.clone(), example, options) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", e);
+                usage()
+            }
+        };
+
+        if all {
+            println!("Day {:02}", day.number);
+        }
+
+        match day.run_file(input, options) {
+            Ok(correct) => failed |= !correct,
+            Err(e) => {
+                eprintln!("Day {:02}: {}", day.number, e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        exit(1);
+    }
+}
+
+fn new(mut args: impl Iterator<Item = String>) {
+    let number = match (args.next().map(|d| d.parse::<u8>()), args.next()) {
+        (Some(Ok(n)), None) => n,
+        _ => usage(),
+    };
+
+    match scaffold::new_day(number) {
+        Ok(dir) => println!("Created {}", dir.display()),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    }
+}
+
+fn main() {
+    let mut args = args().skip(1);
+    match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("new") => new(args),
+        _ => usage(),
+    }
+}
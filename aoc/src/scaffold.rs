@@ -0,0 +1,206 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml");
+const LIB_RS: &str = include_str!("../templates/lib.rs");
+const MAIN_RS: &str = include_str!("../templates/main.rs");
+
+/// The repository root, i.e. the directory of the workspace manifest.
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn render(template: &str, number: u8) -> String {
+    template
+        .replace("{day}", &format!("{:02}", number))
+        .replace("{number}", &number.to_string())
+}
+
+/// The day a line of a registry refers to, such as `"09",` or
+/// `aoc9::DAY,`.
+fn day_of(line: &str) -> Option<u8> {
+    let digits: String = line
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Inserts `entry` into the list that follows the line `header`, keeping the
+/// days in the list ordered. The list ends at a blank line, at the closing
+/// bracket or at the next TOML table.
+fn register(text: &str, header: &str, entry: &str, number: u8) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let start = lines.iter().position(|l| *l == header)? + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|l| l.is_empty() || l.starts_with([']', '[']))
+        .map_or(lines.len(), |i| start + i);
+
+    let at = lines[start..end]
+        .iter()
+        .position(|l| day_of(l).is_some_and(|d| d > number))
+        .map_or(end, |i| start + i);
+    lines.insert(at, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates the crate of day `number` from the templates and adds it to the
+/// workspace and to the runner.
+pub fn new_day(number: u8) -> io::Result<PathBuf> {
+    let root = root();
+    let name = format!("{:02}", number);
+    let dir = root.join(&name);
+    // the directory may already hold a solution in another language
+    if dir.join("Cargo.toml").exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already is a crate", dir.display()),
+        ));
+    }
+
+    let registries = [
+        (
+            root.join("Cargo.toml"),
+            "members = [",
+            format!("    \"{}\",", name),
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]",
+            format!(
+                "aoc{0} = {{ version = \"0.1.0\", path = \"../{0}\" }}",
+                name
+            ),
+        ),
+        (
+            root.join("aoc/src/main.rs"),
+            "const DAYS: &[Day] = &[",
+            format!("    aoc{}::DAY,", name),
+        ),
+    ];
+    // update the registries in memory first, so that a missing list leaves
+    // no unregistered crate behind
+    let mut registered = Vec::new();
+    for (path, header, entry) in registries {
+        let text = fs::read_to_string(&path)?;
+        let text = register(&text, header, &entry, number).ok_or_else(|| {
+            io::Error::other(format!("{} has no line {:?}", path.display(), header))
+        })?;
+        registered.push((path, text));
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), render(CARGO_TOML, number))?;
+    fs::write(dir.join(".gitignore"), "target/\n")?;
+    fs::write(dir.join("src/lib.rs"), render(LIB_RS, number))?;
+    fs::write(dir.join("src/main.rs"), render(MAIN_RS, number))?;
+
+    for (path, text) in registered {
+        fs::write(path, text)?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = "\
+[workspace]
+members = [
+    \"base/rust_base\",
+    \"aoc\",
+    \"12\",
+    \"14\",
+    \"17\",
+]
+";
+
+    const DEPENDENCIES: &str = "\
+[dependencies]
+rust_base = { version = \"0.1.0\", path = \"../base/rust_base\" }
+aoc9 = { version = \"0.1.0\", path = \"../09\" }
+aoc14 = { version = \"0.1.0\", path = \"../14\" }
+
+[dev-dependencies]
+aoc20 = { version = \"0.1.0\", path = \"../20\" }
+";
+
+    const DAYS: &str = "\
+const DAYS: &[Day] = &[
+    aoc08::DAY,
+    aoc9::DAY,
+    aoc14::DAY,
+];
+";
+
+    fn register_days(text: &str, header: &str, entry: impl Fn(&str) -> String) -> String {
+        let text = register(text, header, &entry("13"), 13).unwrap();
+        register(&text, header, &entry("18"), 18).unwrap()
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let members = register_days(MEMBERS, "members = [", |d| format!("    \"{}\",", d));
+        assert_eq!(
+            members.lines().skip(2).collect::<Vec<_>>(),
+            [
+                "    \"base/rust_base\",",
+                "    \"aoc\",",
+                "    \"12\",",
+                "    \"13\",",
+                "    \"14\",",
+                "    \"17\",",
+                "    \"18\",",
+                "]",
+            ]
+        );
+
+        let dependencies = register_days(DEPENDENCIES, "[dependencies]", |d| {
+            format!("aoc{0} = {{ version = \"0.1.0\", path = \"../{0}\" }}", d)
+        });
+        assert_eq!(
+            dependencies.lines().skip(1).take(5).collect::<Vec<_>>(),
+            [
+                "rust_base = { version = \"0.1.0\", path = \"../base/rust_base\" }",
+                "aoc9 = { version = \"0.1.0\", path = \"../09\" }",
+                "aoc13 = { version = \"0.1.0\", path = \"../13\" }",
+                "aoc14 = { version = \"0.1.0\", path = \"../14\" }",
+                "aoc18 = { version = \"0.1.0\", path = \"../18\" }",
+            ]
+        );
+        assert!(dependencies.ends_with(
+            "\n\n[dev-dependencies]\naoc20 = { version = \"0.1.0\", path = \"../20\" }\n"
+        ));
+
+        let days = register_days(DAYS, "const DAYS: &[Day] = &[", |d| {
+            format!("    aoc{}::DAY,", d)
+        });
+        assert_eq!(
+            days,
+            "\
+const DAYS: &[Day] = &[
+    aoc08::DAY,
+    aoc9::DAY,
+    aoc13::DAY,
+    aoc14::DAY,
+    aoc18::DAY,
+];
+"
+        );
+    }
+
+    #[test]
+    fn missing_header() {
+        assert_eq!(register(DAYS, "members = [", "    \"13\",", 13), None);
+    }
+}
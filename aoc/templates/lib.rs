@@ -0,0 +1,43 @@
+use rust_base::{
+    aoc::{ParseError, Parser},
+    runner::Day,
+    solution::Solution,
+};
+
+pub const DAY: Day = Day::of::<Day{day}>();
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {number};
+
+    type Input = Vec<String>;
+    type Answer = i64;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError> {
+        Ok(parser.parse_by_lines(|line| line))
+    }
+
+    fn part1(_input: &Self::Input) -> Option<Self::Answer> {
+        Some(0)
+    }
+
+    fn part2(_input: &Self::Input) -> Option<Self::Answer> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_base::aoc_example;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    fn example() {
+        aoc_example!(Day{day}, EXAMPLE, part1 = 0);
+    }
+}
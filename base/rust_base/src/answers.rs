@@ -0,0 +1,223 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use crate::{aoc::ParseError, runner::Part};
+
+/// The file the runners check answers against, relative to the working
+/// directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The known answers of every day, stored as a small subset of TOML:
+///
+/// ```toml
+/// [04]
+/// part1 = 13
+/// part2 = 30
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+fn index(part: Part) -> usize {
+    match part {
+        Part::One => 0,
+        Part::Two => 1,
+    }
+}
+
+impl Answers {
+    /// Reads the answers file at `path`. A missing file holds no answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => return Err(ParseError::Io { path, source }),
+        };
+
+        text.parse()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ParseError> {
+        let path = path.as_ref().to_path_buf();
+        fs::write(&path, self.to_string()).map_err(|source| ParseError::Io { path, source })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days.get(&day)?[index(part)].as_deref()
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.days.entry(day).or_default()[index(part)] = Some(answer.into());
+    }
+}
+
+/// Writes `answer` as a TOML basic string.
+fn quote(answer: &str) -> String {
+    let mut quoted = String::from('"');
+    for ch in answer.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Reads the basic string at the start of `value`, returning it and what
+/// follows the closing quote.
+fn unquote(value: &str) -> Result<(String, &str), &'static str> {
+    let mut answer = String::new();
+    let mut chars = value.char_indices().skip(1);
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '"' => return Ok((answer, &value[i + 1..])),
+            '\\' => answer.push(match chars.next() {
+                Some((_, '"')) => '"',
+                Some((_, '\\')) => '\\',
+                Some((_, 'n')) => '\n',
+                Some((_, 'r')) => '\r',
+                Some((_, 't')) => '\t',
+                _ => return Err("unsupported escape sequence"),
+            }),
+            ch => answer.push(ch),
+        }
+    }
+    Err("unterminated string")
+}
+
+/// Drops a trailing `# comment`.
+fn strip_comment(s: &str) -> &str {
+    s.split_once('#').map_or(s, |(s, _)| s).trim()
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Self::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |reason: &str| ParseError::malformed(i + 1, line, reason);
+
+            if let Some(table) = line
+                .strip_prefix('[')
+                .and_then(|l| strip_comment(l).strip_suffix(']'))
+            {
+                let number = table.trim().trim_matches('"').parse();
+                day = Some(number.map_err(|_| err("expected a day number"))?);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| err("expected `part1 = <answer>`"))?;
+            let day = day.ok_or_else(|| err("answer outside of a day's table"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(err("expected part1 or part2")),
+            };
+
+            let value = value.trim();
+            let value = if value.starts_with('"') {
+                let (value, rest) = unquote(value).map_err(err)?;
+                if !strip_comment(rest).is_empty() {
+                    return Err(err("unexpected text after the answer"));
+                }
+                value
+            } else {
+                String::from(strip_comment(value))
+            };
+            answers.set(day, part, value);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (day, parts)) in self.days.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{:02}]", day)?;
+
+            for (part, answer) in parts.iter().enumerate() {
+                match answer {
+                    Some(answer) if answer.parse::<i128>().is_ok() => {
+                        writeln!(f, "part{} = {}", part + 1, answer)?
+                    }
+                    Some(answer) => writeln!(f, "part{} = {}", part + 1, quote(answer))?,
+                    None => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+        answers.set(1, Part::One, "142");
+        answers.set(1, Part::Two, "-7");
+        answers.set(4, Part::One, "EFEHJ");
+        answers.set(4, Part::Two, r#"a "quoted" \ path # not a comment"#);
+        answers.set(12, Part::Two, "tab\tand\nnewline");
+
+        let text = answers.to_string();
+        assert_eq!(text.parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn comments() {
+        let text = "\
+# known answers
+[04] # scratchcards
+part1 = 13 # from the example
+part2 = \"30 # kept\"  # dropped
+";
+        let answers: Answers = text.parse().unwrap();
+        assert_eq!(answers.get(4, Part::One), Some("13"));
+        assert_eq!(answers.get(4, Part::Two), Some("30 # kept"));
+    }
+
+    #[test]
+    fn malformed_strings() {
+        assert!("[01]\npart1 = \"open".parse::<Answers>().is_err());
+        assert!("[01]\npart1 = \"a\\x\"".parse::<Answers>().is_err());
+        assert!("[01]\npart1 = \"a\" b".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn malformed_lines_are_numbered() {
+        match "[01]\npart1 = 1\n\npart3 = 2\n".parse::<Answers>() {
+            Err(ParseError::MalformedLine { line, content, .. }) => {
+                assert_eq!((line, content.as_str()), (4, "part3 = 2"))
+            }
+            res => panic!("unexpected result {:?}", res),
+        }
+    }
+}
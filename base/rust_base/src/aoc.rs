@@ -0,0 +1,468 @@
+use std::{
+    env::{self, args},
+    error::Error,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, stdin, BufRead, BufReader, Cursor, Read},
+    iter::MapWhile,
+    path::{Path, PathBuf},
+};
+
+use crate::grid::Grid;
+
+#[derive(Debug)]
+pub enum ParseError {
+    /// No input file was passed on the command line.
+    MissingArgument,
+    /// The input could not be opened or read.
+    Io { path: PathBuf, source: io::Error },
+    /// A line of the input could not be parsed. `line` is one-based.
+    MalformedLine {
+        line: usize,
+        content: String,
+        reason: String,
+    },
+    /// A character of a grid could not be converted. `row` and `col` are
+    /// zero-based grid coordinates.
+    InvalidCell {
+        row: usize,
+        col: usize,
+        ch: char,
+        reason: String,
+    },
+    /// The input reads fine line by line but is invalid as a whole, e.g. a
+    /// map without its start.
+    InvalidInput { reason: String },
+}
+
+impl ParseError {
+    pub fn malformed(line: usize, content: &str, reason: impl Display) -> Self {
+        Self::MalformedLine {
+            line,
+            content: String::from(content),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingArgument => write!(f, "input file must be provided"),
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::MalformedLine {
+                line,
+                content,
+                reason,
+            } => write!(f, "line {}: {} ({:?})", line, reason, content),
+            Self::InvalidCell {
+                row,
+                col,
+                ch,
+                reason,
+            } => write!(f, "row {}, col {}: {} ({:?})", row, col, reason, ch),
+            Self::InvalidInput { reason } => write!(f, "invalid input: {}", reason),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The environment variable that overrides [`INPUTS_DIR`].
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// The directory holding the puzzle inputs, relative to the working
+/// directory.
+pub const INPUTS_DIR: &str = "inputs";
+
+fn inputs_dir() -> PathBuf {
+    env::var_os(INPUTS_ENV).map_or_else(|| PathBuf::from(INPUTS_DIR), PathBuf::from)
+}
+
+/// The puzzle input of `day`, e.g. `inputs/04.txt`.
+pub fn input_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("{:02}.txt", day))
+}
+
+/// The `k`-th example input of `day`, e.g. `inputs/04.example1.txt`.
+pub fn example_path(day: u8, k: usize) -> PathBuf {
+    inputs_dir().join(format!("{:02}.example{}.txt", day, k))
+}
+
+/// Lines of the input, without their terminators. Iteration stops at the
+/// first I/O error.
+pub struct Lines {
+    inner: MapWhile<io::Lines<Box<dyn BufRead>>, KeepOk>,
+}
+
+type KeepOk = fn(io::Result<String>) -> Option<String>;
+
+impl Iterator for Lines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// A block of the input delimited by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// The first line of the block, if it ends with a colon (which is
+    /// stripped), e.g. `seed-to-soil map`.
+    pub header: Option<String>,
+    pub lines: Vec<String>,
+    /// The one-based line number of the first of `lines`, for reporting
+    /// errors with [`ParseError::malformed`].
+    pub line: usize,
+}
+
+impl Section {
+    fn new(mut lines: Vec<String>, mut line: usize) -> Self {
+        let header = lines
+            .first()
+            .and_then(|first| first.trim_end().strip_suffix(':'))
+            .map(String::from);
+        if header.is_some() {
+            lines.remove(0);
+            line += 1;
+        }
+
+        Self {
+            header,
+            lines,
+            line,
+        }
+    }
+}
+
+pub struct Parser {
+    file: Box<dyn BufRead>,
+    path: PathBuf,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Opens the file given as the first command-line argument.
+    pub fn try_new() -> Result<Self, ParseError> {
+        let name = args().nth(1).ok_or(ParseError::MissingArgument)?;
+        Self::try_open(name)
+    }
+
+    /// Opens the file at `path`, where `-` stands for the standard input.
+    pub fn try_open(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        let path = path.as_ref().to_path_buf();
+        if path == Path::new("-") {
+            return Ok(Self::from_reader(stdin().lock()).named("<stdin>"));
+        }
+
+        match File::open(&path) {
+            Ok(file) => Ok(Self::from_reader(BufReader::new(file)).named(path)),
+            Err(source) => Err(ParseError::Io { path, source }),
+        }
+    }
+
+    /// Opens the puzzle input of `day`, see [`input_path`].
+    pub fn for_day(day: u8) -> Result<Self, ParseError> {
+        Self::try_open(input_path(day))
+    }
+
+    /// Opens the `k`-th example input of `day`, see [`example_path`].
+    pub fn for_example(day: u8, k: usize) -> Result<Self, ParseError> {
+        Self::try_open(example_path(day, k))
+    }
+
+    /// Parses an in-memory input, e.g. a puzzle example in a test.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &str) -> Self {
+        Self::from_reader(Cursor::new(String::from(input))).named("<string>")
+    }
+
+    pub fn from_reader(reader: impl BufRead + 'static) -> Self {
+        Self {
+            file: Box::new(reader),
+            path: PathBuf::from("<reader>"),
+        }
+    }
+
+    fn named(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    pub fn parse_by_lines<T>(self, f: impl FnMut(String) -> T) -> Vec<T> {
+        self.lines().map(f).collect()
+    }
+
+    /// Like [`Parser::parse_by_lines`], but also passes the zero-based line
+    /// number to `f`.
+    pub fn parse_by_lines_indexed<T>(self, mut f: impl FnMut(usize, String) -> T) -> Vec<T> {
+        self.lines()
+            .enumerate()
+            .map(|(i, line)| f(i, line))
+            .collect()
+    }
+
+    pub fn parse_all<T>(self, f: impl FnOnce(Lines) -> T) -> T {
+        f(self.lines())
+    }
+
+    /// Splits the input into blank-line-separated [`Section`]s and maps each
+    /// of them with `f`. Runs of several blank lines do not produce empty
+    /// sections.
+    pub fn parse_sections<T>(self, mut f: impl FnMut(Section) -> T) -> Vec<T> {
+        let mut res = Vec::new();
+        let mut block = Vec::new();
+        let mut start = 1;
+
+        for (i, line) in self.lines().enumerate() {
+            if line.trim().is_empty() {
+                if !block.is_empty() {
+                    res.push(f(Section::new(block, start)));
+                    block = Vec::new();
+                }
+            } else {
+                if block.is_empty() {
+                    start = i + 1;
+                }
+                block.push(line);
+            }
+        }
+
+        if !block.is_empty() {
+            res.push(f(Section::new(block, start)));
+        }
+
+        res
+    }
+
+    fn lines(self) -> Lines {
+        Lines {
+            inner: self.file.lines().map_while(Result::ok),
+        }
+    }
+
+    /// Like [`Parser::parse_by_lines`], but stops at the first I/O error or
+    /// line rejected by `f`, reporting its line number and content.
+    pub fn try_parse_by_lines<T, E: Display>(
+        self,
+        mut f: impl FnMut(String) -> Result<T, E>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut res = Vec::new();
+        for (i, line) in self.read_lines()?.into_iter().enumerate() {
+            match f(line.clone()) {
+                Ok(v) => res.push(v),
+                Err(e) => return Err(ParseError::malformed(i + 1, &line, e)),
+            }
+        }
+        Ok(res)
+    }
+
+    /// Reads the whole input up front, so `f` only sees successfully read
+    /// lines. `f` reports bad lines with [`ParseError::malformed`].
+    pub fn try_parse_all<T>(
+        self,
+        f: impl FnOnce(std::vec::IntoIter<String>) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        f(self.read_lines()?.into_iter())
+    }
+
+    /// Reads the input as a rectangular grid of characters, each converted
+    /// with `T::try_from`.
+    pub fn parse_grid<T>(self) -> Grid<T>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        self.try_parse_grid().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_parse_grid<T>(self) -> Result<Grid<T>, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: Display,
+    {
+        self.try_parse_grid_with(T::try_from)
+    }
+
+    pub fn parse_grid_with<T, E: Display>(self, f: impl FnMut(char) -> Result<T, E>) -> Grid<T> {
+        self.try_parse_grid_with(f)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reads the input as a rectangular grid, converting each character with
+    /// `f`. Fails on the first cell `f` rejects and on rows whose length
+    /// differs from the first one.
+    pub fn try_parse_grid_with<T, E: Display>(
+        self,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (row, line) in self.read_lines()?.into_iter().enumerate() {
+            let mut cells = Vec::new();
+            for (col, ch) in line.chars().enumerate() {
+                match f(ch) {
+                    Ok(cell) => cells.push(cell),
+                    Err(e) => {
+                        return Err(ParseError::InvalidCell {
+                            row,
+                            col,
+                            ch,
+                            reason: e.to_string(),
+                        })
+                    }
+                }
+            }
+
+            if let Some(first) = rows.first() {
+                if first.len() != cells.len() {
+                    let reason = format!("expected {} cells, found {}", first.len(), cells.len());
+                    return Err(ParseError::malformed(row + 1, &line, reason));
+                }
+            }
+            rows.push(cells);
+        }
+
+        Ok(Grid::new(rows))
+    }
+
+    /// Reads the whole input into memory, e.g. to parse it repeatedly with
+    /// [`Parser::from_str`].
+    pub fn read_to_string(mut self) -> Result<String, ParseError> {
+        let mut res = String::new();
+        match self.file.read_to_string(&mut res) {
+            Ok(_) => Ok(res),
+            Err(source) => Err(ParseError::Io {
+                path: self.path,
+                source,
+            }),
+        }
+    }
+
+    fn read_lines(self) -> Result<Vec<String>, ParseError> {
+        let path = self.path;
+        self.file
+            .lines()
+            .collect::<io::Result<_>>()
+            .map_err(|source| ParseError::Io { path, source })
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_reader() {
+        let reader = Cursor::new(b"a\nb\n".to_vec());
+        let lines = Parser::from_reader(reader).parse_by_lines(|line| line);
+        assert_eq!(lines, ["a", "b"]);
+    }
+
+    #[test]
+    fn from_str_without_trailing_newline() {
+        let lines = Parser::from_str("a\n\nc").parse_all(|lines| lines.collect::<Vec<_>>());
+        assert_eq!(lines, ["a", "", "c"]);
+    }
+
+    #[test]
+    fn malformed_lines_are_numbered_from_one() {
+        let err = Parser::from_str("1\n2\nx\n4\n")
+            .try_parse_by_lines(|line| line.parse::<u32>())
+            .unwrap_err();
+        match err {
+            ParseError::MalformedLine { line, content, .. } => {
+                assert_eq!((line, content.as_str()), (3, "x"))
+            }
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn sections() {
+        let input = "seeds: 1 2\n\n\n\nfirst map:\n1 2 3\n4 5 6\n\nno header\n";
+        let sections = Parser::from_str(input).parse_sections(|section| section);
+        assert_eq!(
+            sections,
+            [
+                Section {
+                    header: None,
+                    lines: vec![String::from("seeds: 1 2")],
+                    line: 1,
+                },
+                Section {
+                    header: Some(String::from("first map")),
+                    lines: vec![String::from("1 2 3"), String::from("4 5 6")],
+                    line: 6,
+                },
+                Section {
+                    header: None,
+                    lines: vec![String::from("no header")],
+                    line: 9,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn header_only_section() {
+        let sections = Parser::from_str("\nseeds:\n").parse_sections(|section| section);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].header.as_deref(), Some("seeds"));
+        assert!(sections[0].lines.is_empty());
+        assert_eq!(sections[0].line, 3);
+    }
+
+    fn digit(ch: char) -> Result<u32, String> {
+        ch.to_digit(10).ok_or_else(|| String::from("not a digit"))
+    }
+
+    #[test]
+    fn grid() {
+        let grid = Parser::from_str("123\n456\n")
+            .try_parse_grid_with(digit)
+            .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(1, 2), Some(&6));
+    }
+
+    #[test]
+    fn invalid_cell() {
+        let err = Parser::from_str("123\n4x6\n")
+            .try_parse_grid_with(digit)
+            .unwrap_err();
+        match err {
+            ParseError::InvalidCell { row, col, ch, .. } => assert_eq!((row, col, ch), (1, 1, 'x')),
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let err = Parser::from_str("123\n456\n78\n")
+            .try_parse_grid_with(digit)
+            .unwrap_err();
+        match err {
+            ParseError::MalformedLine { line, content, .. } => {
+                assert_eq!((line, content.as_str()), (3, "78"))
+            }
+            e => panic!("unexpected error {}", e),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// The shape of a sequence `s0, s1 = step(s0), s2 = step(s1), ...` that
+/// eventually repeats: after `prefix` states, the next `length` states
+/// repeat forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step at which the sequence is in the same state as at step
+    /// `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+
+    /// Computes the state at step `n` by simulating at most
+    /// `prefix + length` steps.
+    pub fn state_at<S>(&self, initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.equivalent_step(n)).fold(initial, |state, _| step(&state))
+    }
+}
+
+/// A [`Cycle`] together with every state before its second repetition.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    pub cycle: Cycle,
+    states: Vec<S>,
+}
+
+impl<S> History<S> {
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.equivalent_step(n)]
+    }
+}
+
+fn hash_of<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Simulates `step` from `initial` until a state repeats, remembering every
+/// state (but no second copy of it for the lookup) so any step can be looked
+/// up afterwards. Takes `prefix + length` steps.
+pub fn find_cycle<S: Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> History<S> {
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+
+    loop {
+        let indices = seen.entry(hash_of(&state)).or_default();
+        if let Some(&first) = indices.iter().find(|&&i| states[i] == state) {
+            let cycle = Cycle {
+                prefix: first,
+                length: states.len() - first,
+            };
+            return History { cycle, states };
+        }
+
+        indices.push(states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// Brent's algorithm: finds the cycle while keeping only two states in
+/// memory, at the cost of simulating up to about three times as many steps
+/// as [`find_cycle`]. Use [`Cycle::state_at`] to get the state at a step.
+pub fn find_cycle_brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = initial.clone();
+    let mut hare = (0..length).fold(initial, |state, _| step(&state));
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_algorithms_agree() {
+        for prefix in [0, 1, 2, 7, 100] {
+            for length in [1, 2, 3, 16, 99] {
+                let step = |&s: &usize| {
+                    if s + 1 < prefix + length {
+                        s + 1
+                    } else {
+                        prefix
+                    }
+                };
+                let expected = Cycle { prefix, length };
+
+                let history = find_cycle(0, step);
+                assert_eq!(history.cycle, expected);
+                assert_eq!(find_cycle_brent(0, step), expected);
+
+                for n in [0, prefix, prefix + length, 1000] {
+                    assert_eq!(*history.state_at(n), expected.state_at(0, step, n));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A position (or an offset) on a grid, with rows growing downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub row: T,
+    pub col: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(row: T, col: T) -> Self {
+        Self { row, col }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        let abs_diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        abs_diff(self.row, other.row) + abs_diff(self.col, other.col)
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> Point<T> {
+    /// The neighbouring point in the given direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    /// The four orthogonally adjacent points, clockwise from the north.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The eight adjacent points including diagonals, clockwise from the
+    /// north.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().flat_map(move |d| {
+            let side = self.step(d);
+            [side, side.step(d.turn_right())]
+        })
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((row, col): (T, T)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from the north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::East | Self::West)
+    }
+
+    /// The offset of one step in this direction.
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (row, col) = match self {
+            Self::North => (-1, 0),
+            Self::East => (0, 1),
+            Self::South => (1, 0),
+            Self::West => (0, -1),
+        };
+        Point::new(T::from(row), T::from(col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction::*, *};
+
+    #[test]
+    fn neighbours_clockwise() {
+        let around: Vec<(i32, i32)> = Point::new(5, 5).neighbours8().map(Into::into).collect();
+        assert_eq!(
+            around,
+            [
+                (4, 5),
+                (4, 6),
+                (5, 6),
+                (6, 6),
+                (6, 5),
+                (6, 4),
+                (5, 4),
+                (4, 4)
+            ]
+        );
+
+        let sides: Vec<(i32, i32)> = Point::new(5, 5).neighbours4().map(Into::into).collect();
+        assert_eq!(sides, [(4, 5), (5, 6), (6, 5), (5, 4)]);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(
+            Direction::ALL.map(Direction::turn_left),
+            [West, North, East, South]
+        );
+        assert_eq!(
+            Direction::ALL.map(Direction::turn_right),
+            [East, South, West, North]
+        );
+        assert_eq!(
+            Direction::ALL.map(Direction::opposite),
+            [South, West, North, East]
+        );
+    }
+
+    #[test]
+    fn deltas() {
+        for d in Direction::ALL {
+            assert_eq!(d.delta::<i64>() + d.opposite().delta(), Point::new(0, 0));
+        }
+        assert_eq!(North.delta::<i32>(), Point::new(-1, 0));
+        assert_eq!(East.delta::<i32>(), Point::new(0, 1));
+    }
+
+    #[test]
+    fn manhattan_on_unsigned() {
+        let a: Point<usize> = Point::new(2, 7);
+        let b = Point::new(5, 3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!(Point::new(-2i32, 3).manhattan(Point::new(1, -1)), 7);
+    }
+}
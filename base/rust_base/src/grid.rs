@@ -0,0 +1,452 @@
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows. Panics if the rows differ in length.
+    pub fn new(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a grid must have the same length"
+        );
+
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset<I: TryInto<usize>>(&self, row: I, col: I) -> Option<usize> {
+        let (row, col) = (row.try_into().ok()?, col.try_into().ok()?);
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+
+        Some(row * self.width + col)
+    }
+
+    /// Returns the cell at `(row, col)`, or `None` when it lies outside of
+    /// the grid. Negative coordinates are accepted and are always outside.
+    pub fn get<I: TryInto<usize>>(&self, row: I, col: I) -> Option<&T> {
+        self.offset(row, col).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, row: I, col: I) -> Option<&mut T> {
+        self.offset(row, col).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.height).map(move |row| &self[(row, col)])
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Iterates over all cells row by row, together with their `(row, col)`.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// A zero-copy view of the grid that can be rotated, transposed and
+    /// flipped.
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            orientation: Orientation::default(),
+        }
+    }
+
+    pub fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        GridViewMut {
+            grid: self,
+            orientation: Orientation::default(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn rotate_cw(&self) -> Self {
+        self.view().rotate_cw().to_grid()
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
+}
+
+/// How a view's coordinates map onto the underlying grid: first the view
+/// coordinates are swapped if `transpose` is set, then rows and columns of
+/// the grid are counted from the end if `flip_rows` / `flip_cols` are set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Orientation {
+    transpose: bool,
+    flip_rows: bool,
+    flip_cols: bool,
+}
+
+impl Orientation {
+    fn transpose(self) -> Self {
+        Self {
+            transpose: !self.transpose,
+            ..self
+        }
+    }
+
+    fn flip_vertical(self) -> Self {
+        if self.transpose {
+            Self {
+                flip_cols: !self.flip_cols,
+                ..self
+            }
+        } else {
+            Self {
+                flip_rows: !self.flip_rows,
+                ..self
+            }
+        }
+    }
+
+    fn flip_horizontal(self) -> Self {
+        self.transpose().flip_vertical().transpose()
+    }
+
+    fn rotate_cw(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    fn rotate_ccw(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// Width and height of the view onto a grid of the given dimensions.
+    fn dimensions<T>(self, grid: &Grid<T>) -> (usize, usize) {
+        if self.transpose {
+            (grid.height, grid.width)
+        } else {
+            (grid.width, grid.height)
+        }
+    }
+
+    fn offset<T, I: TryInto<usize>>(self, grid: &Grid<T>, row: I, col: I) -> Option<usize> {
+        let (row, col) = (row.try_into().ok()?, col.try_into().ok()?);
+        let (width, height) = self.dimensions(grid);
+        if row >= height || col >= width {
+            return None;
+        }
+
+        let (row, col) = if self.transpose {
+            (col, row)
+        } else {
+            (row, col)
+        };
+        let row = if self.flip_rows {
+            grid.height - 1 - row
+        } else {
+            row
+        };
+        let col = if self.flip_cols {
+            grid.width - 1 - col
+        } else {
+            col
+        };
+
+        Some(row * grid.width + col)
+    }
+}
+
+/// A read-only view of a [`Grid`], possibly rotated, transposed or flipped.
+/// Transforming a view never copies the cells.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    orientation: Orientation,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.orientation.dimensions(self.grid).0
+    }
+
+    pub fn height(&self) -> usize {
+        self.orientation.dimensions(self.grid).1
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, row: I, col: I) -> Option<&'a T> {
+        let grid = self.grid;
+        self.orientation
+            .offset(grid, row, col)
+            .map(|i| &grid.cells[i])
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item = &'a T> {
+        let view = *self;
+        (0..self.width()).map(move |col| view.get(row, col).unwrap())
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &'a T>> {
+        let view = *self;
+        (0..self.height()).map(move |row| view.row(row))
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        Self {
+            orientation: self.orientation.rotate_cw(),
+            ..self
+        }
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self {
+            orientation: self.orientation.rotate_ccw(),
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            orientation: self.orientation.transpose(),
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_horizontal(),
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_vertical(),
+            ..self
+        }
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Copies the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            cells: self.rows().flatten().cloned().collect(),
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", row, col))
+    }
+}
+
+/// A mutable counterpart of [`GridView`], e.g. for moving elements of a grid
+/// in any direction with the code written for one of them.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    orientation: Orientation,
+}
+
+impl<T> GridViewMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.orientation.dimensions(self.grid).0
+    }
+
+    pub fn height(&self) -> usize {
+        self.orientation.dimensions(self.grid).1
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, row: I, col: I) -> Option<&T> {
+        self.orientation
+            .offset(self.grid, row, col)
+            .map(|i| &self.grid.cells[i])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, row: I, col: I) -> Option<&mut T> {
+        self.orientation
+            .offset(self.grid, row, col)
+            .map(|i| &mut self.grid.cells[i])
+    }
+
+    /// Swaps two cells given by their `(row, col)` in the view.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let a = self.orientation.offset(self.grid, a.0, a.1);
+        let b = self.orientation.offset(self.grid, b.0, b.1);
+        match (a, b) {
+            (Some(a), Some(b)) => self.grid.cells.swap(a, b),
+            _ => panic!("cannot swap cells outside of the grid"),
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        Self {
+            orientation: self.orientation.rotate_cw(),
+            ..self
+        }
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        Self {
+            orientation: self.orientation.rotate_ccw(),
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            orientation: self.orientation.transpose(),
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_horizontal(),
+            ..self
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            orientation: self.orientation.flip_vertical(),
+            ..self
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", row, col))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", row, col))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", row, col))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", row, col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 2 3
+    /// 4 5 6
+    fn grid() -> Grid<u8> {
+        Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    fn rows(grid: &Grid<u8>) -> Vec<Vec<u8>> {
+        grid.rows().map(<[u8]>::to_vec).collect()
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = grid();
+        assert_eq!(rows(&grid.rotate_cw()), [[4, 1], [5, 2], [6, 3]]);
+        assert_eq!(rows(&grid.rotate_ccw()), [[3, 6], [2, 5], [1, 4]]);
+        assert_eq!(rows(&grid.transpose()), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(rows(&grid.flip_horizontal()), [[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(rows(&grid.flip_vertical()), [[4, 5, 6], [1, 2, 3]]);
+    }
+
+    #[test]
+    fn four_rotations_are_the_identity() {
+        let grid = grid();
+        let rotated = (0..4).fold(grid.clone(), |g, _| g.rotate_cw());
+        assert_eq!(rotated, grid);
+    }
+
+    #[test]
+    fn composed_views() {
+        let grid = grid();
+        let view = grid.view().rotate_cw().flip_horizontal();
+        assert_eq!((view.width(), view.height()), (2, 3));
+        assert_eq!(view.to_grid(), grid.transpose());
+    }
+}
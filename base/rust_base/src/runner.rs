@@ -0,0 +1,275 @@
+use std::{
+    env::args,
+    fmt::Display,
+    path::{Path, PathBuf},
+    process::exit,
+    str::FromStr,
+};
+
+use crate::{
+    answers::{Answers, ANSWERS_FILE},
+    aoc::{example_path, input_path, ParseError, Parser},
+    solution::Solution,
+    timing::{measure, Timing},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Whether this part should run when `only` is requested, where `None`
+    /// stands for both parts.
+    pub fn selected(self, only: Option<Part>) -> bool {
+        only.is_none_or(|p| p == self)
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => write!(f, "Part 1"),
+            Self::Two => write!(f, "Part 2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            s => Err(format!("unknown part {}", s)),
+        }
+    }
+}
+
+/// What is done with the answers besides printing them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AnswerMode {
+    #[default]
+    Ignore,
+    /// Compare them with the ones in [`ANSWERS_FILE`], see `--check`.
+    Check,
+    /// Store them in [`ANSWERS_FILE`], see `--record`.
+    Record,
+}
+
+/// What a run of a day reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// The part to run, or both when `None`.
+    pub only: Option<Part>,
+    /// How many times each phase is repeated to time it, see `--bench`.
+    pub runs: usize,
+    pub answers: AnswerMode,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            only: None,
+            runs: 1,
+            answers: AnswerMode::Ignore,
+        }
+    }
+}
+
+impl Options {
+    /// Applies the option flag `arg`, taking its value from `args`. Returns
+    /// `Ok(false)` if `arg` is not an option.
+    pub fn parse_flag(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--part" => match args.next().map(|p| p.parse()) {
+                Some(Ok(part)) => self.only = Some(part),
+                _ => return Err(String::from("--part expects 1 or 2")),
+            },
+            "--bench" => match args.next().map(|n| n.parse()) {
+                Some(Ok(n)) if n > 0 => self.runs = n,
+                _ => return Err(String::from("--bench expects a positive number")),
+            },
+            "--check" => self.answers = AnswerMode::Check,
+            "--record" => self.answers = AnswerMode::Record,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// The answer of one part, and how long computing it took.
+struct Outcome {
+    part: Part,
+    answer: String,
+    timing: Timing,
+}
+
+struct Report {
+    parse: Timing,
+    outcomes: Vec<Outcome>,
+}
+
+/// A day's entry in the runner. `run` parses the input and computes the
+/// answers of the selected parts, timing each phase.
+pub struct Day {
+    pub number: u8,
+    run: fn(&str, Options) -> Result<Report, ParseError>,
+}
+
+type SolveFn<S> = fn(&<S as Solution>::Input) -> Option<<S as Solution>::Answer>;
+
+fn run<S: Solution>(input: &str, options: Options) -> Result<Report, ParseError> {
+    let (parsed, parse) = measure(options.runs, || S::parse(Parser::from_str(input)));
+    let parsed = parsed?;
+    let mut outcomes = Vec::new();
+
+    for (part, solve) in [(Part::One, S::part1 as SolveFn<S>), (Part::Two, S::part2)] {
+        if !part.selected(options.only) {
+            continue;
+        }
+        if let (Some(answer), timing) = measure(options.runs, || solve(&parsed)) {
+            outcomes.push(Outcome {
+                part,
+                answer: answer.to_string(),
+                timing,
+            });
+        }
+    }
+
+    Ok(Report { parse, outcomes })
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            run: run::<S>,
+        }
+    }
+
+    /// Runs the day and prints its answers, checking or recording them as
+    /// requested by `options`. Returns `false` if a checked answer is wrong.
+    ///
+    /// Reads the input once, so only parsing is timed and not the I/O.
+    pub fn run_file(&self, input: impl AsRef<Path>, options: Options) -> Result<bool, ParseError> {
+        let input = Parser::try_open(input)?.read_to_string()?;
+        let report = (self.run)(&input, options)?;
+        let mut answers = match options.answers {
+            AnswerMode::Ignore => Answers::default(),
+            _ => Answers::load(ANSWERS_FILE)?,
+        };
+
+        println!("Parse: {}", report.parse);
+        let mut correct = true;
+        for Outcome {
+            part,
+            answer,
+            timing,
+        } in report.outcomes
+        {
+            let verdict = match (options.answers, answers.get(self.number, part)) {
+                (AnswerMode::Check, Some(expected)) if expected == answer => String::from(" OK"),
+                (AnswerMode::Check, Some(expected)) => {
+                    correct = false;
+                    format!(" WRONG, expected {}", expected)
+                }
+                (AnswerMode::Check, None) => String::from(" UNCHECKED"),
+                _ => String::new(),
+            };
+            println!("{}: {} ({}){}", part, answer, timing, verdict);
+
+            if options.answers == AnswerMode::Record {
+                answers.set(self.number, part, answer);
+            }
+        }
+
+        if options.answers == AnswerMode::Record {
+            answers.save(ANSWERS_FILE)?;
+            println!("Recorded in {}", ANSWERS_FILE);
+        }
+        Ok(correct)
+    }
+
+    /// The input to run on: `input` if given, otherwise the `example`-th
+    /// example or the puzzle input of the day in the inputs directory.
+    ///
+    /// [`ANSWERS_FILE`] holds the answers of the day's own input only, so
+    /// checking or recording answers of any other input is refused.
+    pub fn input(
+        &self,
+        input: Option<PathBuf>,
+        example: Option<usize>,
+        options: Options,
+    ) -> Result<PathBuf, String> {
+        if options.answers != AnswerMode::Ignore && (input.is_some() || example.is_some()) {
+            return Err(format!(
+                "--check and --record only work with the day's own input, {}",
+                input_path(self.number).display()
+            ));
+        }
+
+        Ok(match (input, example) {
+            (Some(input), _) => input,
+            (None, Some(k)) => example_path(self.number, k),
+            (None, None) => input_path(self.number),
+        })
+    }
+
+    /// Entry point of the day's own binary, invoked as
+    /// `<binary> [input | --example <K>] [--part <1|2>] [--bench <N>] [--check | --record]`.
+    pub fn main(&self) {
+        let mut input = None;
+        let mut example = None;
+        let mut options = Options::default();
+
+        let mut args = args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--example" {
+                example = match args.next().map(|k| k.parse()) {
+                    Some(Ok(k)) => Some(k),
+                    _ => usage(),
+                };
+                continue;
+            }
+
+            match options.parse_flag(&arg, &mut args) {
+                Ok(true) => {}
+                Ok(false) if input.is_none() => input = Some(PathBuf::from(arg)),
+                Ok(false) => usage(),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    usage()
+                }
+            }
+        }
+
+        let input = match self.input(input, example, options) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", e);
+                usage()
+            }
+        };
+
+        match self.run_file(input, options) {
+            Ok(true) => {}
+            Ok(false) => exit(1),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                exit(1);
+            }
+        }
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: [input | --example <K>] [--part <1|2>] [--bench <N>] [--check | --record]");
+    exit(2)
+}
@@ -0,0 +1,301 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{geometry::Point, grid::Grid};
+
+/// A path found by [`shortest_path`] or [`astar`], including both of its
+/// ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Dijkstra's algorithm. `neighbours` yields the successors of a node with
+/// the (non-negative) cost of moving to them; the search stops at the first
+/// node for which `is_goal` holds. `C::default()` must be the zero cost.
+pub fn shortest_path<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search: like [`shortest_path`], but nodes are explored in the order of
+/// their cost plus `heuristic`, which must never overestimate the remaining
+/// cost to a goal (and should not decrease by more than the cost of a move).
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // nodes are referred to by their index in `nodes` so the queue does not
+    // need them to be `Ord`
+    let mut nodes = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut costs = vec![C::default()];
+    let mut prev: Vec<Option<usize>> = vec![None];
+
+    let mut q = BinaryHeap::new();
+    q.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, cur))) = q.pop() {
+        if cost > costs[cur] {
+            continue;
+        }
+
+        if is_goal(&nodes[cur]) {
+            let mut path = vec![nodes[cur].clone()];
+            let mut at = cur;
+            while let Some(p) = prev[at] {
+                path.push(nodes[p].clone());
+                at = p;
+            }
+            path.reverse();
+            return Some(Path { cost, nodes: path });
+        }
+
+        for (next, step) in neighbours(&nodes[cur]) {
+            let d = cost + step;
+            let i = match indices.get(&next) {
+                Some(&i) if costs[i] <= d => continue,
+                Some(&i) => i,
+                None => {
+                    nodes.push(next.clone());
+                    costs.push(d);
+                    prev.push(None);
+                    indices.insert(next.clone(), nodes.len() - 1);
+                    nodes.len() - 1
+                }
+            };
+
+            costs[i] = d;
+            prev[i] = Some(cur);
+            q.push(Reverse((d + heuristic(&next), d, i)));
+        }
+    }
+
+    None
+}
+
+/// Breadth-first traversal from `start`, see [`bfs`].
+pub struct Bfs<N, F> {
+    queue: VecDeque<(N, usize, Option<N>)>,
+    visited: HashSet<N>,
+    neighbours: F,
+}
+
+/// Visits every node reachable from `start` in the order of increasing
+/// distance, yielding `(node, distance, parent)`. Each node is visited once;
+/// the parent of `start` is `None`.
+pub fn bfs<N, I, F>(start: N, neighbours: F) -> Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    Bfs {
+        queue: VecDeque::from([(start.clone(), 0, None)]),
+        visited: HashSet::from([start]),
+        neighbours,
+    }
+}
+
+impl<N, I, F> Iterator for Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, usize, Option<N>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance, parent) = self.queue.pop_front()?;
+        for next in (self.neighbours)(&node) {
+            if self.visited.insert(next.clone()) {
+                self.queue
+                    .push_back((next, distance + 1, Some(node.clone())));
+            }
+        }
+
+        Some((node, distance, parent))
+    }
+}
+
+/// Marks the cells reachable from `seed` by orthogonal steps over cells for
+/// which `passable` holds. The fill never leaves the grid, and nothing is
+/// reached if the seed itself is not passable.
+pub fn flood_fill<T>(
+    grid: &Grid<T>,
+    seed: (usize, usize),
+    mut passable: impl FnMut(&T) -> bool,
+) -> Grid<bool> {
+    let mut reached = grid.map(|_| false);
+    if !grid.get(seed.0, seed.1).is_some_and(&mut passable) {
+        return reached;
+    }
+
+    let seed = Point::new(seed.0 as isize, seed.1 as isize);
+    let cells = bfs(seed, |cell: &Point<isize>| {
+        cell.neighbours4()
+            .filter(|n| grid.get(n.row, n.col).is_some_and(&mut passable))
+            .collect::<Vec<_>>()
+    });
+    for (cell, _, _) in cells {
+        reached[(cell.row as usize, cell.col as usize)] = true;
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A -1-> B -1-> C -1-> D, and a direct A -5-> D. E is unreachable.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'A' => vec![('D', 5), ('B', 1)],
+            'B' => vec![('C', 1)],
+            'C' => vec![('D', 1)],
+            'E' => vec![('A', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let path = shortest_path('A', edges, |&n| n == 'D').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, ['A', 'B', 'C', 'D']);
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let path = shortest_path('A', edges, |&n| n == 'A').unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 0,
+                nodes: vec!['A']
+            }
+        );
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert_eq!(shortest_path('A', edges, |&n| n == 'E'), None);
+        assert_eq!(astar('A', edges, |_| 0, |&n| n == 'E'), None);
+    }
+
+    #[test]
+    fn astar_on_a_grid() {
+        // entering a cell costs its digit; the cheapest route avoids the 9s
+        let grid = Grid::new(vec![vec![1, 9, 1, 1], vec![1, 9, 1, 9], vec![1, 1, 1, 1]]);
+        let end = Point::new(2isize, 3);
+        let moves = |p: &Point<isize>| {
+            p.neighbours4()
+                .filter_map(|n| grid.get(n.row, n.col).map(|&cost| (n, cost)))
+                .collect::<Vec<_>>()
+        };
+
+        let path = astar(Point::new(0, 0), moves, |p| p.manhattan(end), |&p| p == end).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert_eq!(path.nodes.len(), 6);
+        for pair in path.nodes.windows(2) {
+            assert_eq!(pair[0].manhattan(pair[1]), 1);
+        }
+        assert!(path
+            .nodes
+            .iter()
+            .all(|p| grid[(p.row as usize, p.col as usize)] == 1));
+
+        let dijkstra = shortest_path(Point::new(0, 0), moves, |&p| p == end).unwrap();
+        assert_eq!(dijkstra.cost, path.cost);
+    }
+
+    /// `.` is open, `#` is a wall.
+    fn maze() -> Grid<char> {
+        Grid::new(
+            ["..#.", "#.#.", "...#"]
+                .iter()
+                .map(|row| row.chars().collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn bfs_distances_and_parents() {
+        let grid = maze();
+        let open = |p: &Point<isize>| {
+            p.neighbours4()
+                .filter(|n| grid.get(n.row, n.col) == Some(&'.'))
+                .collect::<Vec<_>>()
+        };
+        let visits: Vec<_> = bfs(Point::new(0, 0), open)
+            .map(|(p, distance, parent)| ((p.row, p.col), distance, parent.map(|q| (q.row, q.col))))
+            .collect();
+
+        assert_eq!(
+            visits,
+            [
+                ((0, 0), 0, None),
+                ((0, 1), 1, Some((0, 0))),
+                ((1, 1), 2, Some((0, 1))),
+                ((2, 1), 3, Some((1, 1))),
+                ((2, 2), 4, Some((2, 1))),
+                ((2, 0), 4, Some((2, 1))),
+            ]
+        );
+    }
+
+    #[test]
+    fn flood_fill_stays_in_its_region() {
+        let grid = maze();
+        let reached = flood_fill(&grid, (2, 2), |&ch| ch == '.');
+        let expected = Grid::new(vec![
+            vec![true, true, false, false],
+            vec![false, true, false, false],
+            vec![true, true, true, false],
+        ]);
+        assert_eq!(reached, expected);
+
+        // the fill reaches the edge of the grid without leaving it
+        let reached = flood_fill(&grid, (0, 3), |&ch| ch == '.');
+        assert_eq!(
+            reached
+                .iter()
+                .filter(|(_, &r)| r)
+                .map(|(cell, _)| cell)
+                .collect::<Vec<_>>(),
+            [(0, 3), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn flood_fill_from_a_wall() {
+        let grid = maze();
+        let reached = flood_fill(&grid, (0, 2), |&ch| ch == '.');
+        assert!(reached.iter().all(|(_, &r)| !r));
+        let reached = flood_fill(&grid, (5, 5), |&ch| ch == '.');
+        assert!(reached.iter().all(|(_, &r)| !r));
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::Display;
+
+use crate::aoc::{ParseError, Parser};
+
+/// The contract every day implements, so drivers, benchmarks and tests can
+/// treat all days alike.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(parser: Parser) -> Result<Self::Input, ParseError>;
+
+    /// `None` if the input has no answer for this part, e.g. an example
+    /// published for the other part only.
+    fn part1(input: &Self::Input) -> Option<Self::Answer>;
+
+    /// `None` for days whose second part is not solved yet, or as for
+    /// [`Solution::part1`].
+    fn part2(_input: &Self::Input) -> Option<Self::Answer> {
+        None
+    }
+}
+
+/// Parses an example input of the day `$day` and asserts the answers of the
+/// given parts, e.g.
+///
+/// ```ignore
+/// aoc_example!(Day04, EXAMPLE, part1 = 13, part2 = 30);
+/// ```
+///
+/// Either part may be left out for examples published for one part only.
+#[macro_export]
+macro_rules! aoc_example {
+    ($day:ty, $input:expr $(, part1 = $part1:expr)? $(, part2 = $part2:expr)? $(,)?) => {{
+        use $crate::solution::Solution as _;
+
+        let input = <$day>::parse($crate::aoc::Parser::from_str($input))
+            .unwrap_or_else(|e| panic!("cannot parse the example: {}", e));
+        $(assert_eq!(<$day>::part1(&input), Some($part1), "part 1");)?
+        $(assert_eq!(<$day>::part2(&input), Some($part2), "part 2");)?
+    }};
+}
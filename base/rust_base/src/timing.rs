@@ -0,0 +1,63 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+/// The durations of one or more runs of the same phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    // sorted, never empty
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs() == 1 {
+            write!(f, "{:.2?}", self.min())
+        } else {
+            write!(
+                f,
+                "min {:.2?}, median {:.2?}, max {:.2?} over {} runs",
+                self.min(),
+                self.median(),
+                self.max(),
+                self.runs()
+            )
+        }
+    }
+}
+
+/// Calls `f` `runs` times (at least once) and returns the result of the last
+/// call together with the duration of every call.
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+    let mut samples = Vec::with_capacity(runs.max(1));
+    let mut last = None;
+    while samples.len() < runs.max(1) {
+        let start = Instant::now();
+        let res = f();
+        samples.push(start.elapsed());
+        // the previous result is dropped outside of the measured time
+        last = Some(res);
+    }
+
+    samples.sort();
+    (last.unwrap(), Timing { samples })
+}